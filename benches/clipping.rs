@@ -0,0 +1,66 @@
+//! Compares the line clippers on the same lines.
+//!
+//! Run with `cargo bench --bench clipping`. The crate keeps its dependencies
+//! small, so this is timed with `Instant` rather than a benchmark framework.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use graphics_rs::cohen_sutherland;
+use graphics_rs::cyrus_beck;
+use graphics_rs::geometry::{Line, Point, Polygon, Rect};
+use graphics_rs::liang_barsky;
+
+const LINES: usize = 100_000;
+const ROUNDS: u32 = 20;
+
+/// Lines with end points spread over four times the area of the window, so
+/// some are inside, some cross it and some miss it.
+fn lines() -> Vec<Line> {
+    // A small linear congruential generator keeps the lines the same on
+    // every run.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        ((state >> 33) % 1024) as i32 - 512
+    };
+    (0..LINES)
+        .map(|_| Line::new(Point::new(next(), next()), Point::new(next(), next())))
+        .collect()
+}
+
+fn bench<F: FnMut(Line) -> Option<Line>>(name: &str, lines: &[Line], mut clip: F) {
+    let mut best = Duration::MAX;
+    let mut accepted = 0;
+    for _ in 0..ROUNDS {
+        let started = Instant::now();
+        accepted = lines.iter().filter(|line| black_box(clip(**line)).is_some()).count();
+        best = best.min(started.elapsed());
+    }
+
+    println!(
+        "{:<32} {:>8.1} ns/line, {} of {} lines accepted",
+        name,
+        best.as_nanos() as f64 / lines.len() as f64,
+        accepted,
+        lines.len()
+    );
+}
+
+fn main() {
+    let lines = lines();
+    let window = Rect::new(Point::new(-256, -256), Point::new(255, 255));
+    let window_polygon = Polygon::from(window);
+    // The same window turned by 30°.
+    let rotated = Polygon::from(vec![
+        Point::new(350, -93),
+        Point::new(93, 350),
+        Point::new(-350, 93),
+        Point::new(-93, -350),
+    ]);
+
+    bench("Cohen-Sutherland", &lines, |line| cohen_sutherland::clip_line(line, &window));
+    bench("Liang-Barsky", &lines, |line| liang_barsky::clip_line(line, &window));
+    bench("Cyrus-Beck, rectangle", &lines, |line| cyrus_beck::clip_line(line, &window_polygon));
+    bench("Cyrus-Beck, rotated rectangle", &lines, |line| cyrus_beck::clip_line(line, &rotated));
+}
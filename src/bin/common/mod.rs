@@ -0,0 +1,44 @@
+//! Helpers shared by the demo binaries.
+
+use std::path::PathBuf;
+
+use image::RgbaImage;
+use log::info;
+
+/// Saves `buffer` to `output` when it is given, or shows it in a window
+/// titled `title` until the window is closed.
+pub fn present(buffer: &RgbaImage, output: Option<PathBuf>, title: &str) {
+    match output {
+        Some(path) => {
+            buffer.save(&path).expect("Could Not Save Image");
+            info!("Saved to {}", path.display());
+        }
+        None => show(buffer, title),
+    }
+}
+
+fn show(buffer: &RgbaImage, title: &str) {
+    use piston_window::*;
+
+    let mut window: PistonWindow = WindowSettings::new(title, [buffer.width(), buffer.height()])
+        .exit_on_esc(true)
+        .graphics_api(OpenGL::V4_5)
+        .resizable(false)
+        .build()
+        .unwrap();
+
+    let mut texture_context = TextureContext {
+        factory: window.factory.clone(),
+        encoder: window.factory.create_command_buffer().into(),
+    };
+
+    let texture: G2dTexture = Texture::from_image(&mut texture_context, buffer, &TextureSettings::new()).unwrap();
+
+    while let Some(event) = window.next() {
+        window.draw_2d(&event, |context, graphics, device| {
+            // Update texture before rendering.
+            texture_context.encoder.flush(device);
+            image(&texture, context.transform, graphics);
+        });
+    }
+}
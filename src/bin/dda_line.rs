@@ -0,0 +1,96 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, warn};
+use simplelog::*;
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_output_arg, take_viewport_arg};
+use graphics_rs::dda_line::draw_line_dda;
+use graphics_rs::geometry::{Point, PointF};
+use graphics_rs::line_compare::{compare_lines, draw_comparison};
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 512;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+
+    if args.len() > 1 && args[1] == "compare" {
+        compare(&args[1..], &mut canvas);
+    } else {
+        let point_a: Point;
+        let point_b: Point;
+
+        if args.len() < 5 {
+            warn!("Not Enough Argument. Using default values.");
+            point_a = Point::new(20, 20);
+            point_b = Point::new(100, 100);
+        } else {
+            let x1 = args[1].parse::<i32>().expect("Could Not Parse X1");
+            let y1 = args[2].parse::<i32>().expect("Could Not Parse Y1");
+            let x2 = args[3].parse::<i32>().expect("Could Not Parse X2");
+            let y2 = args[4].parse::<i32>().expect("Could Not Parse Y2");
+
+            point_a = Point::new(x1, y1);
+            point_b = Point::new(x2, y2);
+        }
+
+        draw_line_dda(point_a, point_b, Rgba([255, 0, 0, 255]), &mut canvas);
+    }
+
+    present(&buffer, output, "DDA Algo");
+}
+
+/// Runs `dda_line compare x1 y1 x2 y2`, which draws the pixels where the DDA
+/// and the Mid-Point line disagree. The coordinates may have decimals.
+fn compare<C: Canvas>(args: &[String], canvas: &mut C) {
+    let point_a: PointF;
+    let point_b: PointF;
+
+    if args.len() < 5 {
+        warn!("Not Enough Argument. Using default values.");
+        point_a = PointF::new(20.0, 20.3);
+        point_b = PointF::new(100.4, 61.7);
+    } else {
+        let x1 = args[1].parse::<f64>().expect("Could Not Parse X1");
+        let y1 = args[2].parse::<f64>().expect("Could Not Parse Y1");
+        let x2 = args[3].parse::<f64>().expect("Could Not Parse X2");
+        let y2 = args[4].parse::<f64>().expect("Could Not Parse Y2");
+
+        point_a = PointF::new(x1, y1);
+        point_b = PointF::new(x2, y2);
+    }
+
+    let comparison = compare_lines(point_a, point_b);
+
+    info!("DDA steps: {}", comparison.dda_steps());
+    info!("Mid-Point steps: {}", comparison.midpoint_steps());
+    info!("DDA max error: {:.4}", comparison.dda_max_error);
+    info!("Mid-Point max error: {:.4}", comparison.midpoint_max_error);
+    for point in &comparison.only_dda {
+        info!("Only DDA x:{} y:{}", point.x, point.y);
+    }
+    for point in &comparison.only_midpoint {
+        info!("Only Mid-Point x:{} y:{}", point.x, point.y);
+    }
+    if comparison.agrees() {
+        info!("Both algorithms drew the same pixels");
+    }
+
+    draw_comparison(&comparison, canvas);
+}
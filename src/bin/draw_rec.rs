@@ -0,0 +1,62 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, warn};
+use simplelog::*;
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_flag, take_output_arg, take_radius_arg, take_viewport_arg};
+use graphics_rs::geometry::{Point, Rect};
+use graphics_rs::rectangle::{draw_rect, Style};
+
+use common::present;
+
+const WINDOW_SIZE: u32 = 512;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+    let style = if take_flag(&mut args, "--fill") { Style::Filled } else { Style::Border(2) };
+    let radius = take_radius_arg(&mut args);
+
+    let width: i32;
+    let height: i32;
+    let mut center = Point::new(0, 0);
+
+    if args.len() < 3 {
+        warn!("Not Enough Argument. Using default values.");
+        width = 250;
+        height = 200;
+    } else {
+        width = args[1].parse::<i32>().expect("Could Not Parse width");
+        height = args[2].parse::<i32>().expect("Could Not Parse height");
+        if args.len() >= 5 {
+            let x = args[3].parse::<i32>().expect("Could Not Parse X");
+            let y = args[4].parse::<i32>().expect("Could Not Parse Y");
+            center = Point::new(x, y);
+        }
+    }
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+
+    create_rectangle(center, width, height, radius, style, &mut canvas);
+
+
+    present(&buffer, output, "rectangle");
+}
+
+fn create_rectangle<C: Canvas>(center: Point, width: i32, height: i32, radius: i32, style: Style, canvas: &mut C) {
+    canvas.clear(Rgba([255, 255, 255, 255]));
+
+    let rect = Rect::from_center(center, width, height);
+    draw_rect(rect, radius, style, Rgba([255, 0, 0, 255]), canvas);
+}
@@ -0,0 +1,80 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_output_arg, take_viewport_arg};
+use graphics_rs::cohen_sutherland::clip_line;
+use graphics_rs::dda_line::draw_line_dda;
+use graphics_rs::geometry::{Line, Point, Rect};
+use graphics_rs::rectangle::{draw_rect, Style};
+
+use common::present;
+
+const WINDOW_SIZE: u32 = 512;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+
+    let width: i32;
+    let height: i32;
+
+    if args.len() < 3 {
+        warn!("Not Enough Argument. Using default values.");
+        width = 300;
+        height = 200;
+    } else {
+        width = args[1].parse::<i32>().expect("Could Not Parse width");
+        height = args[2].parse::<i32>().expect("Could Not Parse height");
+    }
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+    canvas.clear(Rgba([255, 255, 255, 255]));
+
+    let window = Rect::from_center(Point::new(0, 0), width, height);
+    draw_rect(window, 0, Style::Border(2), Rgba([255, 0, 0, 255]), &mut canvas);
+
+    for line in lines() {
+        // The whole line is drawn as rejected first and the part inside of
+        // the window is drawn over it.
+        draw_line_dda(line.start, line.end, Rgba([160, 160, 160, 255]), &mut canvas);
+        match clip_line(line, &window) {
+            Some(clipped) => {
+                info!("Accepted {:?} as {:?}", line, clipped);
+                draw_line_dda(clipped.start, clipped.end, Rgba([0, 160, 0, 255]), &mut canvas);
+            }
+            None => info!("Rejected {:?}", line),
+        }
+    }
+
+    present(&buffer, output, "Cohen-Sutherland Line Clipping");
+}
+
+/// Lines between points around the world origin, some of them through the
+/// clip window and some past it.
+fn lines() -> Vec<Line> {
+    let on_circle = |angle: f64, radius: f64| {
+        let angle = angle.to_radians();
+        Point::new((radius * angle.cos()).round() as i32, (radius * angle.sin()).round() as i32)
+    };
+
+    (0..16)
+        .map(|i| {
+            let angle = f64::from(i) * 22.5;
+            let (sweep, radius) = if i % 2 == 0 { (150.0, 240.0) } else { (60.0, 230.0) };
+            Line::new(on_circle(angle, radius), on_circle(angle + sweep, radius))
+        })
+        .collect()
+}
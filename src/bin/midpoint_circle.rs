@@ -0,0 +1,110 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_flag, take_output_arg, take_viewport_arg};
+use graphics_rs::dash::DashPattern;
+use graphics_rs::geometry::Point;
+use graphics_rs::midpoint_circle::{draw_8way_axis, draw_flower, draw_pie, Outline};
+use graphics_rs::seed_fill::{flood_fill, Connectivity};
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 800;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+    let fill = take_flag(&mut args, "--fill");
+    let outline = if take_flag(&mut args, "--aa") { Outline::AntiAliased } else { Outline::Aliased };
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+
+    if !fill {
+        draw_8way_axis(&DashPattern::center(), Rgba([255, 0, 0, 255]), &mut canvas);
+    }
+
+    if args.len() > 1 && args[1] == "arc" {
+        arc(&args[1..], &mut canvas);
+    } else {
+        let radius = if args.len() < 2 {
+            warn!("Not Enough Argument. Using default values.");
+            350
+        } else {
+            args[1].parse::<i32>().expect("Could Not Parse radius")
+        };
+
+        draw_flower(Point::new(0, 0), radius, outline, Rgba([0, 255, 0, 255]), &mut canvas);
+    }
+
+    if fill {
+        fill_regions(&mut canvas);
+        draw_8way_axis(&DashPattern::center(), Rgba([255, 0, 0, 255]), &mut canvas);
+    }
+
+    present(&buffer, output, "Mid-Point Circle");
+}
+
+/// Runs `midpoint_circle arc radius start end`, which draws the pie slice
+/// from `start` to `end` degrees, counter-clockwise.
+fn arc<C: Canvas>(args: &[String], canvas: &mut C) {
+    let radius: i32;
+    let start: f64;
+    let end: f64;
+
+    if args.len() < 4 {
+        warn!("Not Enough Argument. Using default values.");
+        radius = 350;
+        start = 30.0;
+        end = 300.0;
+    } else {
+        radius = args[1].parse::<i32>().expect("Could Not Parse radius");
+        start = args[2].parse::<f64>().expect("Could Not Parse start angle");
+        end = args[3].parse::<f64>().expect("Could Not Parse end angle");
+    }
+
+    draw_pie(Point::new(0, 0), radius, start, end, Rgba([0, 255, 0, 255]), canvas);
+}
+
+/// Flood fills every region the circles enclose, region by region, in
+/// changing colors. The background around the circles is left as it is.
+fn fill_regions<C: Canvas>(canvas: &mut C) {
+    let colors = [
+        Rgba([255, 200, 0, 255]),
+        Rgba([0, 160, 255, 255]),
+        Rgba([255, 80, 160, 255]),
+        Rgba([140, 90, 255, 255]),
+    ];
+    let empty = Rgba([0, 0, 0, 0]);
+
+    // The background touches the corner of the canvas. It is marked with a
+    // color that is almost transparent while the regions are filled.
+    let background = Rgba([0, 0, 0, 1]);
+    let corner = canvas.world_bounds().min;
+    flood_fill(corner, background, Connectivity::Four, canvas);
+
+    let mut regions = 0;
+    let (viewport, width, height) = (canvas.viewport(), canvas.width(), canvas.height());
+    for (x, y) in (0..height).flat_map(|y| (0..width).map(move |x| (x, y))) {
+        if canvas.get_pixel(x, y) == empty {
+            let seed = viewport.to_world(Point::new(x as i32, y as i32)).expect("Could Not Map pixel");
+            let filled = flood_fill(seed, colors[regions % colors.len()], Connectivity::Four, canvas);
+            info!("Filled region {} with {} pixels", regions, filled);
+            regions += 1;
+        }
+    }
+
+    flood_fill(corner, empty, Connectivity::Four, canvas);
+}
@@ -0,0 +1,52 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::cli::{take_flag, take_output_arg, take_viewport_arg};
+use graphics_rs::dash::DashPattern;
+use graphics_rs::geometry::{Ellipse, Point};
+use graphics_rs::midpoint_ellipse::{draw_ellipse, draw_ellipse_wu};
+use graphics_rs::midpoint_line::draw_center_axis;
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 800;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+    let anti_aliased = take_flag(&mut args, "--aa");
+
+
+    let (rx, ry) = if args.len() < 3 {
+        warn!("Not Enough Argument. Using default values.");
+        (350, 200)
+    } else {
+        let rx = args[1].parse::<i32>().expect("Could Not Parse rx");
+        let ry = args[2].parse::<i32>().expect("Could Not Parse ry");
+        (rx, ry)
+    };
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+
+    draw_center_axis(&DashPattern::center(), Rgba([255, 0, 0, 255]), &mut canvas);
+    let ellipse = Ellipse::new(Point::new(0, 0), rx, ry);
+    if anti_aliased {
+        draw_ellipse_wu(ellipse, Rgba([0, 255, 0, 255]), &mut canvas);
+    } else {
+        draw_ellipse(ellipse, Rgba([0, 255, 0, 255]), &mut canvas);
+    }
+
+    present(&buffer, output, "Mid-Point Ellipse");
+}
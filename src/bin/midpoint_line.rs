@@ -0,0 +1,71 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::cli::{take_dash_arg, take_output_arg, take_viewport_arg};
+use graphics_rs::dash::{draw_dashed, DashPattern};
+use graphics_rs::geometry::{Line, Point};
+use graphics_rs::midpoint_line::{calc_line_points, draw_center_axis, draw_line};
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 800;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+    let dash = take_dash_arg(&mut args);
+
+
+    let line = if args.len() < 5 {
+        warn!("Not Enough Argument. Using default values.");
+
+        // Default
+        let point_a = Point::new(2, 63);
+        let point_b = Point::new(252, 242);
+
+        // Test Case 1
+        // let point_a = Point::new(10, 10);
+        // let point_b = Point::new(60, 50);
+
+        // Test Case 2
+        // let point_a = Point::new(10, -10);
+        // let point_b = Point::new(60, -50);
+
+        // Test Case 3
+        // let point_a = Point::new(-30, -10);
+        // let point_b = Point::new(-100, -40);
+
+        Line::new(point_a, point_b)
+    } else {
+        let x1 = args[1].parse::<i32>().expect("Could Not Parse X1");
+        let y1 = args[2].parse::<i32>().expect("Could Not Parse Y1");
+        let x2 = args[3].parse::<i32>().expect("Could Not Parse X2");
+        let y2 = args[4].parse::<i32>().expect("Could Not Parse Y2");
+
+        let point_a = Point::new(x1, y1);
+        let point_b = Point::new(x2, y2);
+        Line::new(point_a, point_b)
+    };
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+
+    draw_center_axis(&DashPattern::center(), Rgba([255, 0, 0, 255]), &mut canvas);
+    match dash {
+        Some(pattern) => draw_dashed(calc_line_points(line), &mut pattern.dasher(), Rgba([0, 255, 0, 255]), &mut canvas),
+        None => draw_line(line, Rgba([0, 255, 0, 255]), &mut canvas),
+    }
+
+    present(&buffer, output, "Mid-Point Line");
+}
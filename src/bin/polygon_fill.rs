@@ -0,0 +1,118 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_output_arg, take_viewport_arg};
+use graphics_rs::geometry::{Point, Polygon};
+use graphics_rs::midpoint_line::draw_line;
+use graphics_rs::polygon_fill::{fill_polygon, FillRule};
+use graphics_rs::sutherland_hodgman;
+use graphics_rs::weiler_atherton;
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 800;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+
+    if args.len() > 1 && args[1] == "clip" {
+        clip(&mut canvas);
+    } else if args.len() < 2 {
+        warn!("Not Enough Argument. Drawing both fill rules.");
+        draw_star(Point::new(-200, 0), 180, FillRule::EvenOdd, &mut canvas);
+        draw_star(Point::new(200, 0), 180, FillRule::NonZero, &mut canvas);
+    } else {
+        let rule = match args[1].as_str() {
+            "even-odd" => FillRule::EvenOdd,
+            "non-zero" => FillRule::NonZero,
+            _ => panic!("Unknown fill rule {}, expected even-odd or non-zero", args[1]),
+        };
+        draw_star(Point::new(0, 0), 350, rule, &mut canvas);
+    }
+
+    present(&buffer, output, "Polygon Fill");
+}
+
+/// Fills a five pointed star, drawn as a single self-intersecting polygon,
+/// and outlines its edges.
+fn draw_star<C: Canvas>(center: Point, radius: i32, rule: FillRule, canvas: &mut C) {
+    let vertices = (0..5)
+        .map(|i| {
+            let angle = (90.0 + f64::from(i) * 144.0).to_radians();
+            let x = (f64::from(radius) * angle.cos()).round() as i32;
+            let y = (f64::from(radius) * angle.sin()).round() as i32;
+            center + Point::new(x, y)
+        })
+        .collect::<Vec<Point>>();
+    let star = Polygon::from(vertices);
+
+    fill_polygon(&star, rule, Rgba([0, 255, 0, 255]), canvas);
+    for edge in star.edges() {
+        draw_line(edge, Rgba([255, 0, 0, 255]), canvas);
+    }
+}
+
+/// Runs `polygon_fill clip`, which fills a star clipped to a convex window
+/// with Sutherland–Hodgman on the left, and to a concave window with
+/// Weiler–Atherton on the right.
+fn clip<C: Canvas>(canvas: &mut C) {
+    let green = Rgba([0, 255, 0, 255]);
+    let red = Rgba([255, 0, 0, 255]);
+
+    let diamond = Polygon::from(vec![
+        Point::new(-50, 0),
+        Point::new(-200, 150),
+        Point::new(-350, 0),
+        Point::new(-200, -150),
+    ]);
+    let clipped = sutherland_hodgman::clip_polygon(&star(Point::new(-200, 0), 180), &diamond);
+    fill_polygon(&clipped, FillRule::NonZero, green, canvas);
+    for edge in diamond.edges() {
+        draw_line(edge, red, canvas);
+    }
+
+    // Shaped like the letter C, open to the right.
+    let c = Polygon::from(
+        [(40, -150), (360, -150), (360, -60), (130, -60), (130, 60), (360, 60), (360, 150), (40, 150)]
+            .iter()
+            .map(|&point| Point::from(point))
+            .collect::<Vec<Point>>(),
+    );
+    let parts = weiler_atherton::clip_polygon(&star(Point::new(200, 0), 180), &c);
+    info!("Weiler-Atherton cut the star into {} parts", parts.len());
+    for part in &parts {
+        fill_polygon(part, FillRule::NonZero, green, canvas);
+    }
+    for edge in c.edges() {
+        draw_line(edge, red, canvas);
+    }
+}
+
+/// A five pointed star with the inner corners as vertices, so its edges do
+/// not cross.
+fn star(center: Point, radius: i32) -> Polygon {
+    let vertices = (0..10)
+        .map(|i| {
+            let radius = if i % 2 == 0 { f64::from(radius) } else { f64::from(radius) * 0.4 };
+            let angle = (90.0 + f64::from(i) * 36.0).to_radians();
+            center + Point::new((radius * angle.cos()).round() as i32, (radius * angle.sin()).round() as i32)
+        })
+        .collect::<Vec<Point>>();
+    Polygon::from(vertices)
+}
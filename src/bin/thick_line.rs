@@ -0,0 +1,56 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_output_arg, take_viewport_arg};
+use graphics_rs::geometry::{Line, Point};
+use graphics_rs::thick_line::{draw_polyline, draw_thick_line, Cap, Join, Stroke};
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 800;
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+
+    let width = if args.len() < 2 {
+        warn!("Not Enough Argument. Using default values.");
+        24
+    } else {
+        args[1].parse::<u32>().expect("Could Not Parse width")
+    };
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+    canvas.clear(Rgba([255, 255, 255, 255]));
+
+    // The caps on the left, with the thin line they are drawn around.
+    for (i, &cap) in [Cap::Butt, Cap::Square, Cap::Round].iter().enumerate() {
+        let y = 200 - 200 * i as i32;
+        let line = Line::new(Point::new(-300, y - 40), Point::new(-80, y + 40));
+        draw_thick_line(line, &Stroke::new(width).with_cap(cap), Rgba([0, 160, 0, 255]), &mut canvas);
+        draw_thick_line(line, &Stroke::new(1), Rgba([255, 0, 0, 255]), &mut canvas);
+    }
+
+    // The joins on the right.
+    for (i, &join) in [Join::Miter, Join::Bevel, Join::Round].iter().enumerate() {
+        let y = 200 - 200 * i as i32;
+        let zigzag = [Point::new(60, y - 60), Point::new(150, y + 60), Point::new(240, y - 60), Point::new(330, y + 20)];
+        draw_polyline(&zigzag, &Stroke::new(width).with_join(join), Rgba([0, 0, 200, 255]), &mut canvas);
+        draw_polyline(&zigzag, &Stroke::new(1), Rgba([255, 0, 0, 255]), &mut canvas);
+    }
+
+    present(&buffer, output, "Thick Lines");
+}
@@ -0,0 +1,78 @@
+extern crate image;
+
+mod common;
+
+use std::env;
+
+use image::Rgba;
+use log::{info, LevelFilter, warn};
+use simplelog::{Config, TerminalMode, TermLogger};
+
+use graphics_rs::canvas::Canvas;
+use graphics_rs::cli::{take_output_arg, take_viewport_arg};
+use graphics_rs::geometry::{Line, Point, PointF};
+use graphics_rs::midpoint_line::draw_line;
+use graphics_rs::wu_line::draw_line_wu;
+
+use common::present;
+
+static WINDOW_SIZE: u32 = 800;
+
+/// The Mid-Point lines are drawn around the left center and the Wu lines
+/// around the right one.
+const LEFT: PointF = PointF { x: -200.0, y: 0.0 };
+const RIGHT: PointF = PointF { x: 200.0, y: 0.0 };
+
+fn main() {
+    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
+
+    let mut args: Vec<String> = env::args().collect();
+    info!("Running Program from {}", args[0]);
+    let output = take_output_arg(&mut args);
+    let viewport = take_viewport_arg(&mut args, WINDOW_SIZE, WINDOW_SIZE);
+
+    // The lines relative to the centers of both halves. The coordinates may
+    // have decimals.
+    let lines = if args.len() < 5 {
+        warn!("Not Enough Argument. Using default values.");
+        fan()
+    } else {
+        let x1 = args[1].parse::<f64>().expect("Could Not Parse X1");
+        let y1 = args[2].parse::<f64>().expect("Could Not Parse Y1");
+        let x2 = args[3].parse::<f64>().expect("Could Not Parse X2");
+        let y2 = args[4].parse::<f64>().expect("Could Not Parse Y2");
+
+        vec![(PointF::new(x1, y1), PointF::new(x2, y2))]
+    };
+
+    let mut buffer = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    let mut canvas = viewport.canvas(&mut buffer);
+    canvas.clear(Rgba([255, 255, 255, 255]));
+
+    let half = (WINDOW_SIZE / 2) as i32;
+    draw_line(Line::new(Point::new(0, -half), Point::new(0, half)), Rgba([200, 200, 200, 255]), &mut canvas);
+
+    let black = Rgba([0, 0, 0, 255]);
+    for (start, end) in lines {
+        let midpoint = Line::new(offset(start, LEFT).round(), offset(end, LEFT).round());
+        draw_line(midpoint, black, &mut canvas);
+        draw_line_wu(offset(start, RIGHT), offset(end, RIGHT), black, &mut canvas);
+    }
+
+    present(&buffer, output, "Mid-Point and Wu Line");
+}
+
+/// Lines from close to the center out to a circle, at every 15°.
+fn fan() -> Vec<(PointF, PointF)> {
+    (0..24)
+        .map(|i| {
+            let angle = (f64::from(i) * 15.0 + 4.0).to_radians();
+            let at = |radius: f64| PointF::new(radius * angle.cos(), radius * angle.sin());
+            (at(20.0), at(180.0))
+        })
+        .collect()
+}
+
+fn offset(point: PointF, by: PointF) -> PointF {
+    PointF::new(point.x + by.x, point.y + by.y)
+}
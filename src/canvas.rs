@@ -0,0 +1,208 @@
+//! Pixel surfaces the rasterizers draw into.
+//!
+//! Drawing only goes through the [`Canvas`] trait, so the algorithms work the
+//! same on an [`image::RgbaImage`] shown in a window and on a [`FrameBuffer`]
+//! that lives only in memory.
+
+use image::{Rgba, RgbaImage};
+
+use crate::geometry::{Point, Rect};
+use crate::viewport::Viewport;
+
+/// A surface of `width` by `height` RGBA pixels, with (0, 0) at the top left.
+pub trait Canvas {
+    fn width(&self) -> u32;
+
+    fn height(&self) -> u32;
+
+    /// Sets the pixel at (`x`, `y`). Panics if the pixel is outside of the
+    /// canvas.
+    fn put_pixel(&mut self, x: u32, y: u32, color: Rgba<u8>);
+
+    /// Returns the pixel at (`x`, `y`). Panics if the pixel is outside of the
+    /// canvas.
+    fn get_pixel(&self, x: u32, y: u32) -> Rgba<u8>;
+
+    /// Sets every pixel of the canvas to `color`.
+    fn clear(&mut self, color: Rgba<u8>);
+
+    /// Sets the pixel at (`x`, `y`) if it lies on the canvas and does nothing
+    /// otherwise. Returns whether the pixel was set.
+    fn put_pixel_clipped(&mut self, x: i64, y: i64, color: Rgba<u8>) -> bool {
+        let inside = x >= 0 && y >= 0 && x < i64::from(self.width()) && y < i64::from(self.height());
+        if inside {
+            self.put_pixel(x as u32, y as u32, color);
+        }
+        inside
+    }
+
+    /// The mapping from world coordinates to the pixels of the canvas. By
+    /// default the world origin is at the center and the Y axis points up.
+    fn viewport(&self) -> Viewport {
+        Viewport::centered(self.width(), self.height())
+    }
+
+    /// Sets the device pixels the world pixel `point` covers, skipping the
+    /// ones outside of the canvas. Returns whether any pixel was set.
+    fn put_world_pixel(&mut self, point: Point, color: Rgba<u8>) -> bool {
+        let ([x_start, x_end], [y_start, y_end]) = self.viewport().device_pixels(point);
+        let mut any = false;
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                any |= self.put_pixel_clipped(x, y, color);
+            }
+        }
+        any
+    }
+
+    /// Blends `color` over the pixel at (`x`, `y`) with `coverage` as the
+    /// share of the pixel it covers, see [`blend`]. Pixels outside of the
+    /// canvas are skipped. Returns whether the pixel was blended.
+    fn blend_pixel(&mut self, x: i64, y: i64, color: Rgba<u8>, coverage: f64) -> bool {
+        let inside = x >= 0 && y >= 0 && x < i64::from(self.width()) && y < i64::from(self.height());
+        if inside {
+            let below = self.get_pixel(x as u32, y as u32);
+            self.put_pixel(x as u32, y as u32, blend(color, below, coverage));
+        }
+        inside
+    }
+
+    /// Blends `color` over the device pixels the world pixel `point` covers,
+    /// like [`put_world_pixel`](Canvas::put_world_pixel) sets them. Returns
+    /// whether any pixel was blended.
+    fn blend_world_pixel(&mut self, point: Point, color: Rgba<u8>, coverage: f64) -> bool {
+        let ([x_start, x_end], [y_start, y_end]) = self.viewport().device_pixels(point);
+        let mut any = false;
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                any |= self.blend_pixel(x, y, color, coverage);
+            }
+        }
+        any
+    }
+
+    /// The world coordinates of the pixels on the canvas, as seen through
+    /// its viewport.
+    fn world_bounds(&self) -> Rect {
+        let viewport = self.viewport();
+        let corner = |x: u32, y: u32| {
+            viewport
+                .to_world(Point::new(x as i32, y as i32))
+                .unwrap_or_default()
+        };
+        Rect::new(corner(0, 0), corner(self.width().max(1) - 1, self.height().max(1) - 1))
+    }
+}
+
+/// Composites `color` over `below` with the "over" operator, with the alpha of
+/// `color` scaled by `coverage`, which is clamped to 0 to 1 and taken as 0 if
+/// it is NaN. Neither color is premultiplied.
+pub fn blend(color: Rgba<u8>, below: Rgba<u8>, coverage: f64) -> Rgba<u8> {
+    let coverage = if coverage.is_nan() { 0.0 } else { coverage.clamp(0.0, 1.0) };
+    let alpha = f64::from(color[3]) / 255.0 * coverage;
+    let below_alpha = f64::from(below[3]) / 255.0 * (1.0 - alpha);
+    let out_alpha = alpha + below_alpha;
+    if out_alpha == 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+
+    let channel = |i: usize| {
+        let value = (f64::from(color[i]) * alpha + f64::from(below[i]) * below_alpha) / out_alpha;
+        value.round() as u8
+    };
+    Rgba([channel(0), channel(1), channel(2), (out_alpha * 255.0).round() as u8])
+}
+
+impl Canvas for RgbaImage {
+    fn width(&self) -> u32 {
+        RgbaImage::width(self)
+    }
+
+    fn height(&self) -> u32 {
+        RgbaImage::height(self)
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, color: Rgba<u8>) {
+        RgbaImage::put_pixel(self, x, y, color);
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Rgba<u8> {
+        *RgbaImage::get_pixel(self, x, y)
+    }
+
+    fn clear(&mut self, color: Rgba<u8>) {
+        for pixel in self.pixels_mut() {
+            *pixel = color;
+        }
+    }
+}
+
+/// A canvas kept in memory as a plain list of pixels, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameBuffer {
+    width: u32,
+    height: u32,
+    pixels: Vec<Rgba<u8>>,
+}
+
+impl FrameBuffer {
+    /// Creates a frame buffer with every pixel transparent black.
+    ///
+    /// Panics if the number of pixels does not fit into a `usize`.
+    pub fn new(width: u32, height: u32) -> Self {
+        let len = (width as usize)
+            .checked_mul(height as usize)
+            .expect("The frame buffer is too large");
+        Self {
+            width,
+            height,
+            pixels: vec![Rgba([0, 0, 0, 0]); len],
+        }
+    }
+
+    pub fn pixels(&self) -> &[Rgba<u8>] {
+        &self.pixels
+    }
+
+    /// Copies the frame buffer into an image, e.g. to save it to a file.
+    pub fn to_image(&self) -> RgbaImage {
+        RgbaImage::from_fn(self.width, self.height, |x, y| self.get_pixel(x, y))
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "Pixel ({}, {}) is outside of the {}x{} frame buffer",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+impl Canvas for FrameBuffer {
+    fn width(&self) -> u32 {
+        self.width
+    }
+
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, color: Rgba<u8>) {
+        let index = self.index(x, y);
+        self.pixels[index] = color;
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Rgba<u8> {
+        self.pixels[self.index(x, y)]
+    }
+
+    fn clear(&mut self, color: Rgba<u8>) {
+        for pixel in &mut self.pixels {
+            *pixel = color;
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Command line handling shared by the demo binaries.
+
+use std::path::PathBuf;
+
+use crate::dash::DashPattern;
+use crate::geometry::PointF;
+use crate::viewport::{Viewport, YAxis};
+
+/// Removes `--output <file>` from `args` and returns the file.
+///
+/// The binaries render into that file instead of opening a window when it is
+/// given, which lets them run on machines without a GPU.
+pub fn take_output_arg(args: &mut Vec<String>) -> Option<PathBuf> {
+    let index = args.iter().position(|arg| arg == "--output")?;
+    assert!(index + 1 < args.len(), "--output needs a file name");
+
+    let path = args.remove(index + 1);
+    args.remove(index);
+    Some(PathBuf::from(path))
+}
+
+/// Removes the flag `name` from `args` and returns whether it was given.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    match args.iter().position(|arg| arg == name) {
+        Some(index) => {
+            args.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes `--origin <x,y>`, `--zoom <factor>` and `--y-down` from `args`
+/// and returns the viewport of a canvas of `width` by `height` pixels they
+/// describe. The origin is the pixel of the canvas the world origin lands
+/// on, counted from the top left.
+///
+/// Without them the world origin is at the center of the canvas, the Y axis
+/// points up and a world pixel is a pixel on the screen, the same in every
+/// binary.
+pub fn take_viewport_arg(args: &mut Vec<String>, width: u32, height: u32) -> Viewport {
+    let mut viewport = Viewport::centered(width, height);
+    if take_flag(args, "--y-down") {
+        viewport = viewport.with_y_axis(YAxis::Down);
+    }
+    if let Some(index) = args.iter().position(|arg| arg == "--origin") {
+        assert!(index + 1 < args.len(), "--origin needs a point");
+
+        let coordinates: Vec<f64> = args
+            .remove(index + 1)
+            .split(',')
+            .map(|coordinate| coordinate.parse().expect("Could Not Parse origin"))
+            .collect();
+        assert!(coordinates.len() == 2, "--origin needs a point as x,y");
+        args.remove(index);
+        viewport = viewport.with_origin(PointF::new(coordinates[0], coordinates[1]));
+    }
+    if let Some(index) = args.iter().position(|arg| arg == "--zoom") {
+        assert!(index + 1 < args.len(), "--zoom needs a factor");
+
+        let zoom = args.remove(index + 1).parse::<f64>().expect("Could Not Parse zoom");
+        assert!(zoom > 0.0, "--zoom needs a positive factor");
+        args.remove(index);
+        viewport = viewport.with_zoom(zoom);
+    }
+    viewport
+}
+
+/// Removes `--dash <pattern>` from `args` and returns the pattern, which is
+/// either `hidden`, `center` or `dotted`, or the lengths of the dashes and
+/// gaps in pixels separated by commas, e.g. `6,3`.
+pub fn take_dash_arg(args: &mut Vec<String>) -> Option<DashPattern> {
+    let index = args.iter().position(|arg| arg == "--dash")?;
+    assert!(index + 1 < args.len(), "--dash needs a pattern");
+
+    let pattern = match args.remove(index + 1).as_str() {
+        "hidden" => DashPattern::hidden(),
+        "center" => DashPattern::center(),
+        "dotted" => DashPattern::dotted(),
+        lengths => {
+            let lengths: Vec<u32> =
+                lengths.split(',').map(|length| length.parse().expect("Could Not Parse dash length")).collect();
+            DashPattern::new(&lengths)
+        }
+    };
+    args.remove(index);
+    Some(pattern)
+}
+
+/// Removes `--radius <r>` from `args` and returns the radius of rounded
+/// corners, which is 0 for square corners without it.
+pub fn take_radius_arg(args: &mut Vec<String>) -> i32 {
+    match args.iter().position(|arg| arg == "--radius") {
+        Some(index) => {
+            assert!(index + 1 < args.len(), "--radius needs a value");
+            let radius = args.remove(index + 1).parse::<i32>().expect("Could Not Parse radius");
+            args.remove(index);
+            radius
+        }
+        None => 0,
+    }
+}
@@ -0,0 +1,145 @@
+//! Line clipping with the Cohen–Sutherland algorithm.
+//!
+//! Every end point gets an outcode with one bit for each side of the clip
+//! window it lies beyond. A line with both outcodes zero is inside and drawn
+//! as it is, and a line whose outcodes share a bit lies beyond that side and
+//! is rejected. Otherwise one end point outside of the window is moved to
+//! where the line crosses the side it lies beyond, and the line is checked
+//! again.
+
+use std::ops::{BitAnd, BitOr};
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Line, Point, Rect};
+use crate::midpoint_line::draw_line;
+
+/// The sides of the clip window a point lies beyond.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OutCode(pub u8);
+
+impl OutCode {
+    pub const INSIDE: OutCode = OutCode(0);
+    /// Left of `min.x`.
+    pub const LEFT: OutCode = OutCode(1);
+    /// Right of `max.x`.
+    pub const RIGHT: OutCode = OutCode(2);
+    /// Below `min.y`.
+    pub const BOTTOM: OutCode = OutCode(4);
+    /// Above `max.y`.
+    pub const TOP: OutCode = OutCode(8);
+
+    /// The outcode of `point` for the clip window `window`.
+    pub fn of(point: Point, window: &Rect) -> OutCode {
+        Self::of_fraction(i128::from(point.x), i128::from(point.y), 1, window)
+    }
+
+    /// The outcode of the point (`x / denominator`, `y / denominator`), with
+    /// a positive `denominator`.
+    fn of_fraction(x: i128, y: i128, denominator: i128, window: &Rect) -> OutCode {
+        let mut code = OutCode::INSIDE;
+        if x < i128::from(window.min.x) * denominator {
+            code = code | OutCode::LEFT;
+        } else if x > i128::from(window.max.x) * denominator {
+            code = code | OutCode::RIGHT;
+        }
+        if y < i128::from(window.min.y) * denominator {
+            code = code | OutCode::BOTTOM;
+        } else if y > i128::from(window.max.y) * denominator {
+            code = code | OutCode::TOP;
+        }
+        code
+    }
+
+    pub fn is_inside(&self) -> bool {
+        *self == OutCode::INSIDE
+    }
+
+    /// Whether all the sides of `other` are in this outcode.
+    pub fn contains(&self, other: OutCode) -> bool {
+        *self & other == other
+    }
+}
+
+impl BitOr for OutCode {
+    type Output = OutCode;
+
+    fn bitor(self, other: OutCode) -> OutCode {
+        OutCode(self.0 | other.0)
+    }
+}
+
+impl BitAnd for OutCode {
+    type Output = OutCode;
+
+    fn bitand(self, other: OutCode) -> OutCode {
+        OutCode(self.0 & other.0)
+    }
+}
+
+/// Clips `line` to `window`, both ends included. Returns `None` if no part of
+/// the line lies in the window.
+///
+/// The end points are kept in the same order. Where a line crosses a side of
+/// the window between two pixels, the new end point is rounded to the closest
+/// pixel on that side, halves away from zero. The crossings are calculated
+/// exactly on the original line, so the rounding does not add up when a line
+/// is clipped more than once.
+pub fn clip_line(line: Line, window: &Rect) -> Option<Line> {
+    let (x0, y0) = (i128::from(line.start.x), i128::from(line.start.y));
+    let (dx, dy) = (i128::from(line.end.x) - x0, i128::from(line.end.y) - y0);
+
+    // The end points are kept as the fractions `t = numerator / denominator`
+    // along the line, with a positive denominator.
+    let code_at = |(numerator, denominator): (i128, i128)| {
+        OutCode::of_fraction(x0 * denominator + dx * numerator, y0 * denominator + dy * numerator, denominator, window)
+    };
+    let mut start = (0, 1);
+    let mut end = (1, 1);
+    let mut start_code = code_at(start);
+    let mut end_code = code_at(end);
+
+    // Each end point is moved at most twice.
+    for _ in 0..=4 {
+        if (start_code | end_code).is_inside() {
+            return Some(Line::new(line.pixel_at(start.0, start.1), line.pixel_at(end.0, end.1)));
+        } else if !(start_code & end_code).is_inside() {
+            return None;
+        }
+
+        // At least one end point is outside, move that one first. Only sides
+        // the line actually crosses are picked, so the divisor is never zero.
+        let code = if start_code.is_inside() { end_code } else { start_code };
+        let (numerator, denominator) = if code.contains(OutCode::TOP) || code.contains(OutCode::BOTTOM) {
+            let y = i128::from(if code.contains(OutCode::TOP) { window.max.y } else { window.min.y });
+            (y - y0, dy)
+        } else {
+            let x = i128::from(if code.contains(OutCode::RIGHT) { window.max.x } else { window.min.x });
+            (x - x0, dx)
+        };
+        let t = if denominator < 0 { (-numerator, -denominator) } else { (numerator, denominator) };
+
+        if code == start_code {
+            start = t;
+            start_code = code_at(start);
+        } else {
+            end = t;
+            end_code = code_at(end);
+        }
+    }
+    None
+}
+
+/// Clips `line` to `window`, or to the canvas if there is no window, and
+/// draws what is left with the Mid-Point algorithm through the viewport of
+/// the canvas.
+///
+/// Unlike [`draw_line`], which walks every pixel of the line and skips those
+/// outside of the canvas, only the pixels inside the window are walked.
+pub fn draw_clipped_line<C: Canvas>(line: Line, window: Option<Rect>, color: Rgba<u8>, canvas: &mut C) {
+    let window = window.unwrap_or_else(|| canvas.world_bounds());
+    if let Some(clipped) = clip_line(line, &window) {
+        draw_line(clipped, color, canvas);
+    }
+}
@@ -0,0 +1,71 @@
+//! Line clipping with the Cyrus–Beck algorithm.
+//!
+//! This is the parametric clipping of [`liang_barsky`](crate::liang_barsky)
+//! for any convex polygon as the clip window. Every edge of the polygon
+//! limits `t` through the dot product of its outward normal with the line:
+//! the line enters the polygon through the edges it points against and leaves
+//! it through the edges it points along.
+
+use crate::geometry::{Line, Polygon};
+use crate::liang_barsky::{ClipParameters, Parameter};
+
+/// Calculates where `line` enters and leaves the convex polygon `window`,
+/// boundary included. The polygon may be walked either way round.
+///
+/// Returns `None` if no part of the line lies in the window, and for windows
+/// with fewer than three vertices or no area.
+pub fn clip_parameters(line: Line, window: &Polygon) -> Option<ClipParameters> {
+    let area = window.signed_area();
+    if window.vertices.len() < 3 || area == 0.0 {
+        return None;
+    }
+
+    // Products of two differences of `i32` coordinates need more than 64 bits.
+    let (x0, y0) = (i128::from(line.start.x), i128::from(line.start.y));
+    let (dx, dy) = (i128::from(line.end.x) - x0, i128::from(line.end.y) - y0);
+
+    let mut t_enter = Parameter::START;
+    let mut t_exit = Parameter::END;
+    for edge in window.edges() {
+        let (sx, sy) = (i128::from(edge.start.x), i128::from(edge.start.y));
+        let (ex, ey) = (i128::from(edge.end.x) - sx, i128::from(edge.end.y) - sy);
+        // The outward normal is on the right of the edge for counter-clockwise
+        // polygons.
+        let normal = if area > 0.0 { (ey, -ex) } else { (-ey, ex) };
+
+        // The line is inside of the edge where `numerator + t * denominator`
+        // is not positive.
+        let numerator = normal.0 * (x0 - sx) + normal.1 * (y0 - sy);
+        let denominator = normal.0 * dx + normal.1 * dy;
+
+        if denominator == 0 {
+            // Parallel to the edge, and either inside of it or not at all.
+            if numerator > 0 {
+                return None;
+            }
+            continue;
+        }
+
+        let t = Parameter::new(-numerator, denominator);
+        if denominator < 0 {
+            t_enter = t_enter.max(t);
+        } else {
+            t_exit = t_exit.min(t);
+        }
+    }
+
+    if t_enter <= t_exit {
+        Some(ClipParameters { t_enter, t_exit })
+    } else {
+        None
+    }
+}
+
+/// Clips `line` to the convex polygon `window`, boundary included. Returns
+/// `None` if no part of the line lies in the window.
+///
+/// The end points are kept in the same order and rounded to the closest
+/// pixels, which may lie just outside of a slanted edge.
+pub fn clip_line(line: Line, window: &Polygon) -> Option<Line> {
+    clip_parameters(line, window).map(|parameters| parameters.apply(line))
+}
@@ -0,0 +1,172 @@
+//! Dashed and dotted lines.
+//!
+//! A [`DashPattern`] switches the pixels of a line on and off as they are
+//! walked, so it works on the pixels of any of the rasterizers, e.g.
+//! [`calc_line_points`], [`calculate_points`](crate::dda_line::calculate_points)
+//! or [`calc_circle_points`](crate::midpoint_circle::calc_circle_points). The
+//! lengths of the dashes are counted in pixels, not along the exact line.
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Line, Point};
+use crate::midpoint_line::calc_line_points;
+
+/// A repeating pattern of dashes and gaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashPattern {
+    /// The lengths of the dashes and the gaps between them, alternating and
+    /// starting with a dash.
+    runs: Vec<u32>,
+    /// How far into the pattern the first pixel is.
+    phase: u32,
+}
+
+impl DashPattern {
+    /// Dashes and gaps of the lengths in `dashes`, alternating and starting
+    /// with a dash, like the dash array of SVG. An odd number of lengths is
+    /// repeated once to make it even, so `[2]` is 2 pixels on and 2 off.
+    /// Without any length, or with only zeros, the line is solid.
+    pub fn new(dashes: &[u32]) -> Self {
+        let mut runs = dashes.to_vec();
+        if runs.iter().all(|&run| run == 0) {
+            runs = vec![1, 0];
+        } else if runs.len() % 2 == 1 {
+            runs.extend_from_slice(dashes);
+        }
+        Self { runs, phase: 0 }
+    }
+
+    /// The pattern of a 16 bit `mask`, where a set bit draws a pixel, from
+    /// the lowest bit up, like the line stipple of OpenGL.
+    pub fn from_mask(mask: u16) -> Self {
+        let mut runs = vec![];
+        let mut on = true;
+        let mut length = 0;
+        for bit in 0..16 {
+            if (mask >> bit & 1 == 1) != on {
+                runs.push(length);
+                on = !on;
+                length = 0;
+            }
+            length += 1;
+        }
+        runs.push(length);
+        if runs.len() % 2 == 1 {
+            runs.push(0);
+        }
+        Self { runs, phase: 0 }
+    }
+
+    /// A line without gaps.
+    pub fn solid() -> Self {
+        Self::new(&[])
+    }
+
+    /// Short dashes for hidden edges.
+    pub fn hidden() -> Self {
+        Self::new(&[6, 4])
+    }
+
+    /// Long and short dashes for center lines and axes.
+    pub fn center() -> Self {
+        Self::new(&[16, 4, 4, 4])
+    }
+
+    /// Single pixels with gaps of 2.
+    pub fn dotted() -> Self {
+        Self::new(&[1, 2])
+    }
+
+    /// Starts the pattern `phase` pixels into it.
+    pub fn with_phase(mut self, phase: u32) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// The length of the pattern before it repeats.
+    pub fn period(&self) -> u64 {
+        self.runs.iter().map(|&run| u64::from(run)).sum()
+    }
+
+    /// Whether the pixel `position` pixels into the pattern is drawn.
+    pub fn is_on(&self, position: u64) -> bool {
+        let mut position = (position + u64::from(self.phase)) % self.period();
+        for (i, &run) in self.runs.iter().enumerate() {
+            if position < u64::from(run) {
+                return i % 2 == 0;
+            }
+            position -= u64::from(run);
+        }
+        unreachable!("the position is within the period")
+    }
+
+    /// A dasher that walks the pattern from its phase.
+    pub fn dasher(&self) -> Dasher {
+        Dasher {
+            pattern: self.clone(),
+            position: 0,
+        }
+    }
+}
+
+/// Walks a [`DashPattern`] pixel by pixel. The same dasher can be used for
+/// several lines, which continues the pattern from one to the next.
+#[derive(Debug, Clone)]
+pub struct Dasher {
+    pattern: DashPattern,
+    position: u64,
+}
+
+impl Dasher {
+    /// Whether the next pixel is drawn. Moves on to the pixel after it.
+    pub fn next_is_on(&mut self) -> bool {
+        let on = self.pattern.is_on(self.position);
+        self.position = (self.position + 1) % self.pattern.period();
+        on
+    }
+
+    /// Keeps the pixels of `points` the pattern draws, in order.
+    pub fn apply<I: IntoIterator<Item = Point>>(&mut self, points: I) -> Vec<Point> {
+        points.into_iter().filter(|_| self.next_is_on()).collect()
+    }
+}
+
+/// Draws the pixels of `points` that `dasher` draws onto `canvas` through its
+/// viewport, e.g. the pixels of a line or a circle.
+pub fn draw_dashed<C: Canvas, I: IntoIterator<Item = Point>>(
+    points: I,
+    dasher: &mut Dasher,
+    color: Rgba<u8>,
+    canvas: &mut C,
+) {
+    for point in dasher.apply(points) {
+        canvas.put_world_pixel(point, color);
+    }
+}
+
+/// Calculates the pixels of the polyline through `points` that `dasher`
+/// draws, with Mid-Point lines between the points.
+///
+/// The pattern runs on around the corners: the pixel of a point where two
+/// lines meet is only walked once.
+pub fn calc_dashed_polyline_points(points: &[Point], dasher: &mut Dasher) -> Vec<Point> {
+    let mut pixels = vec![];
+    for (i, pair) in points.windows(2).enumerate() {
+        let line = calc_line_points(Line::new(pair[0], pair[1]));
+        let skip = if i == 0 { 0 } else { 1 };
+        pixels.extend(dasher.apply(line.into_iter().skip(skip)));
+    }
+    if points.len() == 1 {
+        pixels.extend(dasher.apply(points.iter().copied()));
+    }
+    pixels
+}
+
+/// Draws the polyline through `points` onto `canvas` through its viewport,
+/// dashed by `dasher`.
+pub fn draw_dashed_polyline<C: Canvas>(points: &[Point], dasher: &mut Dasher, color: Rgba<u8>, canvas: &mut C) {
+    for point in calc_dashed_polyline_points(points, dasher) {
+        canvas.put_world_pixel(point, color);
+    }
+}
@@ -1,130 +1,79 @@
-extern crate piston_window;
-
-use std::env;
-use std::option::Option::Some;
-
-use log::{info, trace, warn};
-use piston_window::*;
-use simplelog::*;
-
-static WINDOW_SIZE: u32 = 512;
-
-fn main() {
-    let mut window: PistonWindow = WindowSettings::new("DDA Algo", [WINDOW_SIZE; 2])
-        .exit_on_esc(true)
-        .graphics_api(OpenGL::V4_5)
-        .automatic_close(true)
-        .resizable(true)
-        .vsync(true)
-        .build()
-        .unwrap();
-
-    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
-
-
-    let args: Vec<String> = env::args().collect();
-    info!("Running Program from {}", args[0]);
-
-    let point_a: Point;
-    let point_b: Point;
-
-    if args.len() < 5 {
-        warn!("Not Enough Argument. Using default values.");
-        point_a = Point { x: 20, y: 20 };
-        point_b = Point { x: 100, y: 100 };
-    } else {
-        let x1 = args[1].parse::<i32>().expect("Could Not Parse X1");
-        let y1 = args[2].parse::<i32>().expect("Could Not Parse Y1");
-        let x2 = args[3].parse::<i32>().expect("Could Not Parse X2");
-        let y2 = args[4].parse::<i32>().expect("Could Not Parse Y2");
-
-        point_a = Point { x: x1, y: y1 };
-        point_b = Point { x: x2, y: y2 };
+//! Line drawing with the Digital Differential Analyzer (DDA) algorithm.
+
+use image::Rgba;
+use log::trace;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Point, PointF};
+
+/// Draws the line between `point_a` and `point_b` onto `canvas`.
+///
+/// The points are world coordinates, mapped through the viewport of the
+/// canvas. Pixels outside of the canvas are skipped.
+pub fn draw_line_dda<C: Canvas>(point_a: Point, point_b: Point, color: Rgba<u8>, canvas: &mut C) {
+    for point in calculate_points(point_a, point_b) {
+        trace!("x:{} y:{}", point.x, point.y);
+        canvas.put_world_pixel(point, color);
     }
-
-
-    draw_line_dda(point_a, point_b, &mut window);
 }
 
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-fn draw_line_dda(point_a: Point, point_b: Point, window: &mut PistonWindow) {
-    let result = calculate_points(point_a, point_b);
-
-
-    for r in &result {
-        trace!("x:{} y:{}", r[0], r[1]);
+/// Calculates the pixels of the line from `point_a` to `point_b`.
+///
+/// The line is walked one pixel at a time along its major axis, from
+/// `point_a` towards `point_b`, while the other coordinate is advanced by the
+/// slope and rounded. A coordinate exactly halfway between two pixels is
+/// rounded towards `point_a`, which gives the same pixels as
+/// [`calc_line_points`](crate::midpoint_line::calc_line_points) for integer
+/// end points.
+///
+/// The end points may lie anywhere inside a pixel. Vertical, horizontal and
+/// single pixel lines are handled without dividing by zero, and lines with a
+/// non-finite coordinate have no pixels.
+pub fn calculate_points<P: Into<PointF>>(point_a: P, point_b: P) -> Vec<Point> {
+    let a = point_a.into();
+    let b = point_b.into();
+
+    if ![a.x, a.y, b.x, b.y].iter().all(|c| c.is_finite()) {
+        return vec![];
     }
 
+    let start = a.round();
+    let end = b.round();
+    if start == end {
+        return vec![start];
+    }
 
-    while let Some(event) = window.next() {
-        window.draw_2d(&event, |context, graphics, _device| {
-            clear([0.0; 4], graphics);
-
-            for r in &result {
-                draw_point(r[0], r[1], context, graphics);
-            }
-        });
-    };
-}
-
-fn calculate_points(point_a: Point, point_b: Point) -> Vec<[f64; 2]> {
-    let mut points: Vec<[f64; 2]> = vec![];
-
-    let delta_x = point_a.x - point_b.x;
-    let delta_y = point_a.y - point_b.y;
-    let m = delta_y as f64 / delta_x as f64;
-
-    let mut x;
-    let mut y;
-    let limit;
-
-    if m <= 1.0 && m > -1.0 {
-        if point_a.x < point_b.x {
-            x = point_a.x as f64;
-            y = point_a.y as f64;
-            limit = point_b.x + 1;
-        } else {
-            x = point_b.x as f64;
-            y = point_b.y as f64;
-            limit = point_a.x + 1;
-        }
+    let delta_x = b.x - a.x;
+    let delta_y = b.y - a.y;
 
-        while x < limit as f64 {
-            points.push([x, y.round()]);
-            x += 1.0;
-            y += m;
-        }
+    if delta_x.abs() >= delta_y.abs() {
+        walk(start.x, end.x, a.x, a.y, delta_y / delta_x)
+            .map(|(x, y)| Point::new(x, y))
+            .collect()
     } else {
-        if point_a.y < point_b.y {
-            y = point_a.y as f64;
-            x = point_a.x as f64;
-            limit = point_b.y + 1;
-        } else {
-            y = point_b.y as f64;
-            x = point_b.x as f64;
-            limit = point_a.y + 1;
-        }
-
-        while y < limit as f64 {
-            points.push([x.round(), y]);
-            y += 1.0;
-            x += 1.0 / m;
-        }
+        walk(start.y, end.y, a.y, a.x, delta_x / delta_y)
+            .map(|(y, x)| Point::new(x, y))
+            .collect()
     }
-    points
 }
 
-fn draw_point(x: f64, y: f64, context: Context, graphics: &mut G2d) {
-    let red = [1.0, 0.0, 0.0, 1.0];
-
-    rectangle(
-        red,
-        [x, y, 1.0, 1.0],
-        context.transform,
-        graphics,
-    )
+/// Steps the major coordinate from `first` to `last` and yields it together
+/// with the rounded minor coordinate. The line passes through
+/// (`major`, `minor`) with `slope` as the change of the minor coordinate per
+/// major step.
+fn walk(first: i32, last: i32, major: f64, minor: f64, slope: f64) -> impl Iterator<Item = (i32, i32)> {
+    let step = if last >= first { 1 } else { -1 };
+    let count = (last - first).abs() + 1;
+
+    // The minor coordinate at the center of the first pixel.
+    let mut y = minor + (first as f64 - major) * slope;
+    let increment = slope * step as f64;
+    let towards_start = if increment > 0.0 { f64::floor } else { f64::ceil };
+
+    (0..count).map(move |i| {
+        let x = first + i * step;
+        let rounded = if (y - y.floor() - 0.5).abs() < 1e-9 { towards_start(y) } else { y.round() };
+        y += increment;
+        (x, rounded as i32)
+    })
 }
@@ -0,0 +1,313 @@
+//! Integer geometry shared by all of the rasterizers.
+//!
+//! Coordinates are world coordinates, which the
+//! [`Viewport`](crate::viewport::Viewport) of a canvas maps to its pixels.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(self.x.neg(), self.y.neg())
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<[i32; 2]> for Point {
+    fn from([x, y]: [i32; 2]) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<Point> for [i32; 2] {
+    fn from(point: Point) -> Self {
+        [point.x, point.y]
+    }
+}
+
+/// A point with sub-pixel precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PointF {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PointF {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// The pixel the point falls into.
+    pub fn round(&self) -> Point {
+        Point::new(self.x.round() as i32, self.y.round() as i32)
+    }
+}
+
+impl From<Point> for PointF {
+    fn from(point: Point) -> Self {
+        PointF::new(point.x as f64, point.y as f64)
+    }
+}
+
+impl From<(f64, f64)> for PointF {
+    fn from((x, y): (f64, f64)) -> Self {
+        PointF::new(x, y)
+    }
+}
+
+/// A line segment from `start` to `end`, both ends included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    /// The vector from `start` to `end`.
+    pub fn delta(&self) -> Point {
+        self.end - self.start
+    }
+
+    /// The same segment, walked from `end` to `start`.
+    pub fn reversed(&self) -> Line {
+        Line::new(self.end, self.start)
+    }
+
+    /// The point at `t` along the line, where `t` is 0 at `start` and 1 at
+    /// `end`.
+    pub fn point_at(&self, t: f64) -> PointF {
+        let start = PointF::from(self.start);
+        let end = PointF::from(self.end);
+        PointF::new(start.x + t * (end.x - start.x), start.y + t * (end.y - start.y))
+    }
+
+    /// The pixel closest to the point at `t = numerator / denominator` along
+    /// the line, calculated exactly. Halves are rounded away from zero, like
+    /// [`f64::round`]. `t` must be between 0 and 1.
+    pub fn pixel_at(&self, numerator: i128, denominator: i128) -> Point {
+        let (numerator, denominator) = if denominator < 0 { (-numerator, -denominator) } else { (numerator, denominator) };
+        let coordinate = |start: i32, end: i32| {
+            let scaled = i128::from(start) * denominator + (i128::from(end) - i128::from(start)) * numerator;
+            let rounded = (2 * scaled.abs() + denominator) / (2 * denominator);
+            (rounded * scaled.signum()) as i32
+        };
+        Point::new(coordinate(self.start.x, self.end.x), coordinate(self.start.y, self.end.y))
+    }
+}
+
+impl From<(Point, Point)> for Line {
+    fn from((start, end): (Point, Point)) -> Self {
+        Line::new(start, end)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: i32,
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: i32) -> Self {
+        Self { center, radius }
+    }
+}
+
+/// An axis aligned ellipse with the radius `rx` along X and `ry` along Y.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Ellipse {
+    pub center: Point,
+    pub rx: i32,
+    pub ry: i32,
+}
+
+impl Ellipse {
+    pub fn new(center: Point, rx: i32, ry: i32) -> Self {
+        Self { center, rx, ry }
+    }
+}
+
+/// An axis aligned rectangle spanning from `min` to `max`, both corners
+/// included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    /// Creates the rectangle with `a` and `b` as opposite corners.
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// Creates the rectangle of `width` by `height` centered on `center`, so
+    /// that its [`width`](Self::width) and [`height`](Self::height) are the
+    /// ones given. For odd sizes the extra unit goes to the right and the top.
+    pub fn from_center(center: Point, width: i32, height: i32) -> Self {
+        let min = center - Point::new(width / 2, height / 2);
+        Self::new(min, min + Point::new(width, height))
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    /// The four corners, counter-clockwise starting from `min`.
+    pub fn corners(&self) -> [Point; 4] {
+        [
+            self.min,
+            Point::new(self.max.x, self.min.y),
+            self.max,
+            Point::new(self.min.x, self.max.y),
+        ]
+    }
+}
+
+/// A horizontal run of pixels on row `y`, from `x_start` to `x_end`, both
+/// ends included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub y: i32,
+    pub x_start: i32,
+    pub x_end: i32,
+}
+
+impl Span {
+    pub fn new(y: i32, x_start: i32, x_end: i32) -> Self {
+        Self { y, x_start, x_end }
+    }
+
+    /// The number of pixels in the span.
+    pub fn len(&self) -> u32 {
+        (i64::from(self.x_end) - i64::from(self.x_start) + 1).max(0) as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x_end < self.x_start
+    }
+}
+
+/// A closed polygon. The last vertex connects back to the first one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    pub vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// The edges of the polygon, including the closing edge.
+    pub fn edges(&self) -> impl Iterator<Item = Line> + '_ {
+        let count = self.vertices.len();
+        (0..count).map(move |i| Line::new(self.vertices[i], self.vertices[(i + 1) % count]))
+    }
+
+    /// The area of the polygon, positive if the vertices go counter-clockwise
+    /// and negative if they go clockwise.
+    pub fn signed_area(&self) -> f64 {
+        let twice: i128 = self
+            .edges()
+            .map(|edge| {
+                i128::from(edge.start.x) * i128::from(edge.end.y) - i128::from(edge.end.x) * i128::from(edge.start.y)
+            })
+            .sum();
+        twice as f64 / 2.0
+    }
+
+    /// The same polygon with its vertices in the opposite order.
+    pub fn reversed(&self) -> Polygon {
+        Polygon::new(self.vertices.iter().rev().copied().collect())
+    }
+}
+
+impl From<Vec<Point>> for Polygon {
+    fn from(vertices: Vec<Point>) -> Self {
+        Polygon::new(vertices)
+    }
+}
+
+impl From<Rect> for Polygon {
+    fn from(rect: Rect) -> Self {
+        Polygon::new(rect.corners().to_vec())
+    }
+}
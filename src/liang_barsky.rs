@@ -0,0 +1,156 @@
+//! Line clipping with the Liang–Barsky algorithm.
+//!
+//! The line is written as `start + t * (end - start)` for `t` from 0 to 1.
+//! Every side of the clip window limits `t` from one direction: the line
+//! enters the window through the sides it points into and leaves it through
+//! the others. The largest entering `t` and the smallest leaving `t` are where
+//! the clipped line starts and ends.
+
+use std::cmp::Ordering;
+
+use crate::geometry::{Line, Point, Rect};
+
+/// A parameter `t` along a line, as the exact fraction
+/// `numerator / denominator`.
+#[derive(Debug, Clone, Copy)]
+pub struct Parameter {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Parameter {
+    /// The start of the line.
+    pub const START: Parameter = Parameter { numerator: 0, denominator: 1 };
+    /// The end of the line.
+    pub const END: Parameter = Parameter { numerator: 1, denominator: 1 };
+
+    /// The fraction `numerator / denominator`. Panics if `denominator` is 0.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "The denominator of a parameter is 0");
+        if denominator < 0 {
+            Self { numerator: -numerator, denominator: -denominator }
+        } else {
+            Self { numerator, denominator }
+        }
+    }
+
+    /// The parameter as a floating point number.
+    pub fn value(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// The pixel closest to the point at this parameter along `line`.
+    pub fn pixel_on(&self, line: Line) -> Point {
+        line.pixel_at(self.numerator, self.denominator)
+    }
+}
+
+impl Ord for Parameter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The cross products may not fit into an `i128`, so the whole parts are
+        // compared first, and then the inverse of the remainders the other way
+        // round, like in a continued fraction.
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        let mut reversed = false;
+        loop {
+            let ordering = match (a.div_euclid(b).cmp(&c.div_euclid(d)), a.rem_euclid(b), c.rem_euclid(d)) {
+                (Ordering::Equal, 0, 0) => Ordering::Equal,
+                (Ordering::Equal, 0, _) => Ordering::Less,
+                (Ordering::Equal, _, 0) => Ordering::Greater,
+                (Ordering::Equal, r, s) => {
+                    a = b;
+                    b = r;
+                    c = d;
+                    d = s;
+                    reversed = !reversed;
+                    continue;
+                }
+                (ordering, _, _) => ordering,
+            };
+            return if reversed { ordering.reverse() } else { ordering };
+        }
+    }
+}
+
+impl PartialOrd for Parameter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Parameter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Parameter {}
+
+/// The part of a line that is left after clipping, as the parameters where
+/// the line enters and leaves the clip window. The line starts at `t` 0 and
+/// ends at `t` 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipParameters {
+    pub t_enter: Parameter,
+    pub t_exit: Parameter,
+}
+
+impl ClipParameters {
+    /// The part of `line` between `t_enter` and `t_exit`, with the end points
+    /// rounded to the closest pixels exactly like in
+    /// [`cohen_sutherland::clip_line`](crate::cohen_sutherland::clip_line).
+    pub fn apply(&self, line: Line) -> Line {
+        Line::new(self.t_enter.pixel_on(line), self.t_exit.pixel_on(line))
+    }
+}
+
+/// Calculates where `line` enters and leaves `window`, both ends included.
+/// Returns `None` if no part of the line lies in the window.
+pub fn clip_parameters(line: Line, window: &Rect) -> Option<ClipParameters> {
+    let (x0, y0) = (i64::from(line.start.x), i64::from(line.start.y));
+    let (dx, dy) = (i64::from(line.end.x) - x0, i64::from(line.end.y) - y0);
+
+    // One `p * t <= q` limit for the left, right, bottom and top side.
+    let limits = [
+        (-dx, x0 - i64::from(window.min.x)),
+        (dx, i64::from(window.max.x) - x0),
+        (-dy, y0 - i64::from(window.min.y)),
+        (dy, i64::from(window.max.y) - y0),
+    ];
+
+    let mut t_enter = Parameter::START;
+    let mut t_exit = Parameter::END;
+    for &(p, q) in &limits {
+        if p == 0 {
+            // Parallel to the side, and either inside of it or not at all.
+            if q < 0 {
+                return None;
+            }
+            continue;
+        }
+
+        let t = Parameter::new(i128::from(q), i128::from(p));
+        if p < 0 {
+            t_enter = t_enter.max(t);
+        } else {
+            t_exit = t_exit.min(t);
+        }
+    }
+
+    if t_enter <= t_exit {
+        Some(ClipParameters { t_enter, t_exit })
+    } else {
+        None
+    }
+}
+
+/// Clips `line` to `window`, both ends included. Returns `None` if no part of
+/// the line lies in the window.
+///
+/// The end points are kept in the same order and rounded to the closest
+/// pixels, and are the same as those of
+/// [`cohen_sutherland::clip_line`](crate::cohen_sutherland::clip_line).
+pub fn clip_line(line: Line, window: &Rect) -> Option<Line> {
+    clip_parameters(line, window).map(|parameters| parameters.apply(line))
+}
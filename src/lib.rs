@@ -0,0 +1,26 @@
+//! Basic rasterization algorithms from the CSE423 Computer Graphics course.
+//!
+//! Every algorithm lives in its own module so it can be used outside of the
+//! demo binaries under `src/bin`.
+
+pub mod canvas;
+pub mod cli;
+pub mod cohen_sutherland;
+pub mod cyrus_beck;
+pub mod dash;
+pub mod dda_line;
+pub mod geometry;
+pub mod liang_barsky;
+pub mod line_compare;
+pub mod midpoint_circle;
+pub mod midpoint_ellipse;
+pub mod midpoint_line;
+pub mod polygon_fill;
+pub mod rectangle;
+pub mod seed_fill;
+pub mod sutherland_hodgman;
+pub mod thick_line;
+pub mod transform;
+pub mod viewport;
+pub mod weiler_atherton;
+pub mod wu_line;
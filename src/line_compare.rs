@@ -0,0 +1,112 @@
+//! Side by side comparison of the DDA and the Mid-Point line.
+//!
+//! Both algorithms give the same pixels for integer end points. They start to
+//! disagree once the end points lie inside a pixel: the DDA follows the exact
+//! line, while the Mid-Point line only sees the pixels the end points fall
+//! into.
+
+use std::collections::HashSet;
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::dda_line::calculate_points;
+use crate::geometry::{Line, Point, PointF};
+use crate::midpoint_line::calc_line_points;
+
+/// The pixels both algorithms drew for the same segment, and how far they
+/// stray from the ideal line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineComparison {
+    pub dda: Vec<Point>,
+    pub midpoint: Vec<Point>,
+    /// Pixels only the DDA drew, in drawing order.
+    pub only_dda: Vec<Point>,
+    /// Pixels only the Mid-Point line drew, in drawing order.
+    pub only_midpoint: Vec<Point>,
+    /// The largest perpendicular distance of a DDA pixel center from the line.
+    pub dda_max_error: f64,
+    /// The largest perpendicular distance of a Mid-Point pixel center from
+    /// the line.
+    pub midpoint_max_error: f64,
+}
+
+impl LineComparison {
+    /// Whether both algorithms drew exactly the same pixels.
+    pub fn agrees(&self) -> bool {
+        self.only_dda.is_empty() && self.only_midpoint.is_empty()
+    }
+
+    /// The number of steps the DDA took, one per pixel.
+    pub fn dda_steps(&self) -> usize {
+        self.dda.len()
+    }
+
+    /// The number of steps the Mid-Point line took, one per pixel.
+    pub fn midpoint_steps(&self) -> usize {
+        self.midpoint.len()
+    }
+}
+
+/// Rasterizes the segment from `point_a` to `point_b` with both algorithms.
+///
+/// The Mid-Point line is given the pixels the end points fall into.
+pub fn compare_lines<P: Into<PointF>>(point_a: P, point_b: P) -> LineComparison {
+    let a = point_a.into();
+    let b = point_b.into();
+
+    let dda = calculate_points(a, b);
+    let midpoint = calc_line_points(Line::new(a.round(), b.round()));
+
+    LineComparison {
+        only_dda: difference(&dda, &midpoint),
+        only_midpoint: difference(&midpoint, &dda),
+        dda_max_error: max_error(&dda, a, b),
+        midpoint_max_error: max_error(&midpoint, a, b),
+        dda,
+        midpoint,
+    }
+}
+
+/// Draws the pixels both algorithms agree on in white, the DDA only pixels in
+/// red and the Mid-Point only pixels in green.
+///
+/// The points are world coordinates, like in
+/// [`draw_line_dda`](crate::dda_line::draw_line_dda). Pixels outside of the
+/// canvas are skipped.
+pub fn draw_comparison<C: Canvas>(comparison: &LineComparison, canvas: &mut C) {
+    let colored = comparison
+        .dda
+        .iter()
+        .map(|point| (point, Rgba([255, 255, 255, 255])))
+        .chain(comparison.only_dda.iter().map(|point| (point, Rgba([255, 0, 0, 255]))))
+        .chain(comparison.only_midpoint.iter().map(|point| (point, Rgba([0, 255, 0, 255]))));
+
+    for (point, color) in colored {
+        canvas.put_world_pixel(*point, color);
+    }
+}
+
+fn difference(points: &[Point], other: &[Point]) -> Vec<Point> {
+    let other: HashSet<&Point> = other.iter().collect();
+    points.iter().filter(|point| !other.contains(point)).copied().collect()
+}
+
+/// The largest distance of a pixel center from the infinite line through `a`
+/// and `b`, or from `a` if both are the same point.
+fn max_error(points: &[Point], a: PointF, b: PointF) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length = dx.hypot(dy);
+
+    points
+        .iter()
+        .map(|point| {
+            let (px, py) = (point.x as f64 - a.x, point.y as f64 - a.y);
+            if length == 0.0 {
+                px.hypot(py)
+            } else {
+                (px * dy - py * dx).abs() / length
+            }
+        })
+        .fold(0.0, f64::max)
+}
@@ -1,162 +1,465 @@
-extern crate image;
-extern crate piston_window;
+//! Circle drawing with the Mid-Point algorithm.
+//!
+//! Only the second octant (from the top of the circle down to the 45° line)
+//! is calculated; the other seven are filled in through the 8-way symmetry of
+//! the circle.
 
-use std::env;
+use std::convert::TryFrom;
 use std::ops::Neg;
 
-use image::{ImageBuffer, Rgba};
-use log::{info, LevelFilter, warn};
-use piston_window::*;
-use simplelog::{Config, TerminalMode, TermLogger};
-
-static WINDOW_SIZE: u32 = 800;
-
-fn main() {
-    let mut window: PistonWindow = WindowSettings::new("Mid-Point Circle", [WINDOW_SIZE; 2])
-        .exit_on_esc(true)
-        .graphics_api(OpenGL::V4_5)
-        .resizable(false)
-        .build()
-        .unwrap();
-
-    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
-
-    let args: Vec<String> = env::args().collect();
-    info!("Running Program from {}", args[0]);
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::dash::DashPattern;
+use crate::geometry::{Circle, Line, Point, Span};
+use crate::midpoint_line::draw_line;
+use crate::polygon_fill::fill_spans;
+use crate::transform::Transform2D;
+
+/// Adds the point (`x`, `y`) of the second octant to all eight octants, by
+/// calling `add` with the octant and the point mirrored into it.
+///
+/// Neighbouring octants meet at the axis (`x == 0`) and at the diagonals
+/// (`x == y`). Those points are only added to the octant that starts there
+/// when walking counter-clockwise, so every pixel is added exactly once.
+fn add_with_mirror_points<F: FnMut(usize, [i64; 2])>(x: i64, y: i64, mut add: F) {
+    if x > y {
+        // Past the diagonal, the point is the mirror of the previous one.
+        return;
+    }
 
+    add(0, [y, x]);
+    add(2, [x.neg(), y]);
+    add(4, [y.neg(), x.neg()]);
+    add(6, [x, y.neg()]);
 
-    let radius : i16;
+    if x > 0 && x < y {
+        add(1, [x, y]);
+        add(3, [y.neg(), x]);
+        add(5, [x.neg(), y.neg()]);
+        add(7, [y, x.neg()]);
+    }
+}
 
-    if args.len() < 2 {
-        warn!("Not Enough Argument. Using default values.");
-        radius = 350;
+/// Walks the second octant of a circle of `radius` around the origin, from
+/// (0, `radius`) to the 45° line, and calls `visit` with every point. Nothing
+/// is visited for a radius below 1.
+fn walk_second_octant<F: FnMut(i64, i64)>(radius: i64, mut visit: F) {
+    if radius < 1 {
+        return;
+    }
 
-    } else {
-        let r = args[1].parse::<i16>().expect("Could Not Parse radius");
-        if r > 350 {
-            radius = 350;
+    let mut d = 1 - radius;
+    let mut _x = 0;
+    let mut _y = radius;
+    visit(_x, _y);
+    while _x < _y {
+        if d < 0 {
+            d = d + 2 * _x + 3;
+            _x += 1;
         } else {
-            radius = r;
+            d = d + 2 * _x - 2 * _y + 5;
+            _x += 1;
+            _y -= 1;
         }
+        visit(_x, _y);
     }
+}
 
-    let mut canvas = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+/// Calculates the points of `circle`, once each, in order around the
+/// perimeter.
+///
+/// The points start at the rightmost point of the circle and go
+/// counter-clockwise, with the Y axis pointing up. See [`calc_circle_octants`]
+/// for the same points split up into octants.
+pub fn calc_circle_points(circle: Circle) -> Vec<Point> {
+    calc_circle_octants(circle).concat()
+}
 
-    let mut texture_context = TextureContext {
-        factory: window.factory.clone(),
-        encoder: window.factory.create_command_buffer().into(),
-    };
+/// Calculates the points of `circle` as eight arcs, one per octant.
+///
+/// Octant `n` covers the angles after `n * 45°` up to `(n + 1) * 45°`, and
+/// its points are ordered counter-clockwise. The even octants also start
+/// with the point at `n * 45°`, so every point of the circle is in exactly
+/// one arc.
+///
+/// The decision variable is kept in `i64`, so any `i32` radius works without
+/// overflowing. Points that would not fit in an `i32` are left out. A circle
+/// with a negative radius has no points.
+pub fn calc_circle_octants(circle: Circle) -> [Vec<Point>; 8] {
+    let mut octants: [Vec<[i64; 2]>; 8] = Default::default();
+
+    if circle.radius == 0 {
+        octants[0].push([0, 0]);
+    } else {
+        walk_second_octant(i64::from(circle.radius), |x, y| {
+            add_with_mirror_points(x, y, |octant, point| octants[octant].push(point))
+        });
+    }
 
-    let mut texture: G2dTexture =
-        Texture::from_image(&mut texture_context, &canvas, &TextureSettings::new()).unwrap();
+    // The odd octants were walked clockwise.
+    for octant in octants.iter_mut().skip(1).step_by(2) {
+        octant.reverse();
+    }
 
+    let mut result: [Vec<Point>; 8] = Default::default();
+    for (points, octant) in result.iter_mut().zip(octants.iter()) {
+        *points = octant.iter().filter_map(|&point| offset(circle.center, point)).collect();
+    }
+    result
+}
 
-    draw_8way_axis(&mut canvas);
-    draw_flower(0,0, radius, &mut canvas);
+/// Calculates the pixels of the anti-aliased outline of `circle`, each with
+/// its coverage between 0 and 1, in the same order as
+/// [`calc_circle_points`].
+///
+/// Like [`calc_wu_points`](crate::wu_line::calc_wu_points), every column of
+/// the second octant is split between the two pixels the exact circle passes
+/// between, and the other seven octants are its mirror images. Pixels with no
+/// coverage and pixels that would not fit in an `i32` are left out. A circle
+/// of radius 0 is a single pixel and one with a negative radius has none.
+pub fn calc_wu_circle_points(circle: Circle) -> Vec<(Point, f64)> {
+    let radius = i64::from(circle.radius);
+    let mut octants: [Vec<([i64; 2], f64)>; 8] = Default::default();
+
+    if radius == 0 {
+        octants[0].push(([0, 0], 1.0));
+    }
+    // Up to the 45° line, where the exact circle passes x == y. Every pixel
+    // is at least as high as it is far right, as add_with_mirror_points
+    // expects.
+    let mut x = 0;
+    while radius > 0 && 2 * x * x <= radius * radius {
+        let exact_y = ((radius * radius - x * x) as f64).sqrt();
+        for (y, coverage) in split_coverage(exact_y) {
+            if coverage > 0.0 {
+                add_with_mirror_points(x, y, |octant, point| octants[octant].push((point, coverage)));
+            }
+        }
+        x += 1;
+    }
 
+    // The odd octants were walked clockwise.
+    for octant in octants.iter_mut().skip(1).step_by(2) {
+        octant.reverse();
+    }
 
-    texture.update(&mut texture_context, &canvas).unwrap();
+    octants
+        .iter()
+        .flatten()
+        .filter_map(|&(point, coverage)| Some((offset(circle.center, point)?, coverage)))
+        .collect()
+}
 
-    while let Some(event) = window.next() {
-        window.draw_2d(&event, |context, graphics, device| {
-            // Update texture before rendering.
-            texture_context.encoder.flush(device);
-            image(&texture, context.transform, graphics);
-        });
-    }
+/// Splits a coverage of 1 between the two pixels on either side of the
+/// coordinate `at`, in proportion to how close `at` is to their centers.
+pub(crate) fn split_coverage(at: f64) -> [(i64, f64); 2] {
+    let below = at.floor();
+    [(below as i64, 1.0 - (at - below)), (below as i64 + 1, at - below)]
 }
 
-fn draw_flower(x: i16, y: i16, radius: i16, canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-    draw_circle(x,y, radius, canvas);
-    draw_child_circles(x, y, radius, canvas);
+/// Moves `point` from around the origin to around `center`, or `None` if it
+/// does not fit in an `i32`.
+pub(crate) fn offset(center: Point, [x, y]: [i64; 2]) -> Option<Point> {
+    let x = i32::try_from(x + i64::from(center.x)).ok()?;
+    let y = i32::try_from(y + i64::from(center.y)).ok()?;
+    Some(Point::new(x, y))
 }
 
-fn draw_child_circles(x: i16, y: i16, radius: i16, canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-    draw_circle(x + radius / 2, y, radius / 2, canvas);
-    draw_circle((x + radius / 2).neg(), y, radius / 2, canvas);
-    draw_circle(x, y + radius / 2, radius / 2, canvas);
-    draw_circle(x, (y + radius / 2).neg(), radius / 2, canvas);
+/// Calculates the spans that fill the disc of `circle`, from the bottom row up.
+///
+/// Every row of the disc is one span, reaching out to the outline drawn by
+/// [`calc_circle_points`], so every pixel is covered exactly once. Rows that
+/// would not fit in an `i32` are left out and the spans are cut off at the
+/// `i32` limits. A circle with a negative radius has no spans.
+///
+/// Each row is worked out on its own, without walking the outline. See
+/// [`calc_circle_spans_between`] for only some of the rows of a large disc.
+pub fn calc_circle_spans(circle: Circle) -> Vec<Span> {
+    calc_circle_spans_between(circle, i32::MIN, i32::MAX)
+}
 
-    let _x = ((radius - x) as f32 / 2.82) as i16;
-    let _y = ((radius - y) as f32 / 2.82) as i16;
+/// Calculates the spans of [`calc_circle_spans`] on the rows from `y_min` up
+/// to `y_max`. The time and memory only grow with the rows asked for, so any
+/// `i32` radius works.
+pub fn calc_circle_spans_between(circle: Circle, y_min: i32, y_max: i32) -> Vec<Span> {
+    let radius = i64::from(circle.radius);
+    if radius < 0 {
+        return vec![];
+    }
 
-    draw_circle(_x, _y, radius / 2, canvas);
-    draw_circle(_x.neg(), _y, radius / 2, canvas);
-    draw_circle(_x, _y.neg(), radius / 2, canvas);
-    draw_circle(_x.neg(), _y.neg(), radius / 2, canvas);
+    spans_from_half_widths(circle.center, radius, i64::from(y_min), i64::from(y_max), |dy| {
+        disc_half_width(radius, dy)
+    })
 }
 
-fn draw_8way_axis(canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-    for i in 0..WINDOW_SIZE {
-        let red = Rgba([255, 0, 0, 255]);
-        canvas.put_pixel(i, WINDOW_SIZE / 2, red);
-        canvas.put_pixel(WINDOW_SIZE / 2, i, red);
-        canvas.put_pixel(i,i, red);
-        canvas.put_pixel(WINDOW_SIZE - i - 1, i, red);
+/// The half width of the row `dy` rows above the center of a disc of
+/// `radius`, as far as the outline of [`walk_second_octant`] and its mirror
+/// images reach on that row.
+///
+/// The walk moves on to (x + 1, y) while the midpoint (x + 1, y - ½) lies
+/// inside of the circle, so the y of column x is the smallest y with
+/// x² + (y + ½)² ≥ radius², but at most one below the y of the column before.
+/// The walk stops at the first column whose y is not above it. A row is
+/// reached by the column with its number, mirrored about the 45° line, and
+/// by the columns the walk takes along the row.
+fn disc_half_width(radius: i64, dy: i64) -> i64 {
+    // Everything is doubled to stay integers, which needs more than 64 bits
+    // for radii close to the `i32` limit.
+    let diameter2 = 4 * i128::from(radius).pow(2);
+    let dy = i128::from(dy.abs());
+
+    // The smallest y of column x with (2y + 1)² ≥ (2 radius)² - (2x)².
+    let lowest = |x: i128| {
+        let rest = diameter2 - 4 * x * x;
+        let root = isqrt(rest.max(0));
+        (if root * root < rest { root + 1 } else { root }) / 2
+    };
+    let walked = |x: i128| if x == 0 { i128::from(radius) } else { lowest(x).max(lowest(x - 1) - 1) };
+
+    // The column the walk stops at, starting from the first one with
+    // (2x)² + (2x + 1)² ≥ (2 radius)², where its lowest y is not above it.
+    let mut last = isqrt(diameter2 / 8);
+    while last > 0 && 4 * (last - 1).pow(2) + (2 * last - 1).pow(2) >= diameter2 {
+        last -= 1;
+    }
+    while 4 * last.pow(2) + (2 * last + 1).pow(2) < diameter2 {
+        last += 1;
     }
+    while walked(last) > last {
+        last += 1;
+    }
+
+    let mut half_width = if dy <= last { walked(dy) } else { 0 };
+    let last_y = walked(last);
+    if dy == last_y {
+        half_width = half_width.max(last);
+    } else if dy > last_y {
+        // Up to the last column the walk follows the lowest y, so the row
+        // reaches to the largest x with (2x)² < (2 radius)² - (2 dy - 1)².
+        half_width = half_width.max(isqrt(diameter2 - (2 * dy - 1).pow(2) - 1) / 2);
+    }
+    half_width as i64
 }
 
-fn add_with_mirror_points(x: i16, y: i16, circle_points: &mut Vec<[i16;2]>){
-    circle_points.push([x,y]);
-    circle_points.push([y,x]);
-    circle_points.push([y, x.neg()]);
-    circle_points.push([x, y.neg()]);
-    circle_points.push([x.neg(), y.neg()]);
-    circle_points.push([y.neg(), x.neg()]);
-    circle_points.push([y.neg(), x]);
-    circle_points.push([x.neg(), y]);
+/// The largest integer whose square is at most `n`, for `n` ≥ 0.
+fn isqrt(n: i128) -> i128 {
+    let mut root = (n as f64).sqrt() as i128;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root
 }
 
-fn draw_circle(x: i16, y: i16, radius: i16, canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
+/// Turns the half widths of the rows of a shape that is symmetric about both
+/// axis through `center` into its spans, for the rows from `y_min` to
+/// `y_max`. `half_width` is called with the rows above the center, from 0 for
+/// the row of the center up to `rows`.
+pub(crate) fn spans_from_half_widths<F: Fn(i64) -> i64>(
+    center: Point,
+    rows: i64,
+    y_min: i64,
+    y_max: i64,
+    half_width: F,
+) -> Vec<Span> {
+    let clamp = |x: i64| x.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+    let center_y = i64::from(center.y);
+    let first = (center_y - rows).max(y_min).max(i64::from(i32::MIN));
+    let last = (center_y + rows).min(y_max).min(i64::from(i32::MAX));
+
+    (first..=last)
+        .map(|y| {
+            let half_width = half_width((y - center_y).abs());
+            let x_start = clamp(i64::from(center.x) - half_width);
+            let x_end = clamp(i64::from(center.x) + half_width);
+            Span::new(y as i32, x_start, x_end)
+        })
+        .collect()
+}
 
-    let circle_points = calc_circle_points(x, y, radius);
+/// Draws `circle` onto `canvas` through its viewport. The circle is clipped
+/// to the canvas pixel by pixel.
+pub fn draw_circle<C: Canvas>(circle: Circle, color: Rgba<u8>, canvas: &mut C) {
+    for point in calc_circle_points(circle) {
+        canvas.put_world_pixel(point, color);
+    }
+}
 
-    for point in &circle_points {
-        draw_point(point[0],point[1] , canvas);
+/// Blends the anti-aliased outline of `circle` onto `canvas` through its
+/// viewport, with the coverage of each pixel as its opacity.
+pub fn draw_circle_wu<C: Canvas>(circle: Circle, color: Rgba<u8>, canvas: &mut C) {
+    for (point, coverage) in calc_wu_circle_points(circle) {
+        canvas.blend_world_pixel(point, color, coverage);
     }
 }
 
+/// How the outline of a shape is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outline {
+    /// One solid pixel per step of the Mid-Point algorithm.
+    Aliased,
+    /// The pixels next to the exact outline, blended by their coverage.
+    AntiAliased,
+}
 
-fn draw_point(x: u32, y: u32, canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-    let green = Rgba([0, 255, 0, 255]);
-    canvas.put_pixel(x, y, green);
+impl Outline {
+    fn draw_circle<C: Canvas>(self, circle: Circle, color: Rgba<u8>, canvas: &mut C) {
+        match self {
+            Outline::Aliased => draw_circle(circle, color, canvas),
+            Outline::AntiAliased => draw_circle_wu(circle, color, canvas),
+        }
+    }
 }
 
-fn calc_circle_points(x: i16, y: i16, radius: i16) -> Vec<[u32;2]>{
-    let mut circle_points: Vec<[i16;2]> = vec![];
+/// Calculates the points of the arc of `circle` from `start_angle` to
+/// `end_angle`, in order.
+///
+/// The angles are in degrees, counter-clockwise from the positive X axis, and
+/// the arc goes counter-clockwise from the start to the end, so 350° to 10°
+/// is a 20° arc. The arc starts and ends at the pixels of the circle closest
+/// to the exact angles, which for an arc just short of a full turn can be the
+/// same pixel twice. The arc covers the whole circle once the end is a full
+/// turn or more past the start. Octants that lie away from the arc are
+/// skipped without looking at their points.
+pub fn calc_arc_points(circle: Circle, start_angle: f64, end_angle: f64) -> Vec<Point> {
+    let sweep = end_angle - start_angle;
+    if !sweep.is_finite() {
+        return vec![];
+    } else if sweep >= 360.0 {
+        return calc_circle_points(circle);
+    }
+    let start = start_angle.rem_euclid(360.0);
+    let sweep = sweep.rem_euclid(360.0);
+
+    // Angles are measured counter-clockwise from the start.
+    let offset = |angle: f64| (angle - start).rem_euclid(360.0);
+
+    let mut candidates: Vec<(f64, Point)> = vec![];
+    for (n, octant) in calc_circle_octants(circle).iter().enumerate() {
+        // Keep the neighbouring octants of the arc, which may hold the pixel
+        // closest to an end.
+        let middle = offset(n as f64 * 45.0 + 22.5);
+        let distance = if middle <= sweep { 0.0 } else { (middle - sweep).min(360.0 - middle) };
+        if distance > 22.5 + 45.0 {
+            continue;
+        }
 
-    let mut d:i16 = 1 - radius;
-    let mut _x = 0;
-    let mut _y = radius as i16;
-    add_with_mirror_points(_x, _y, &mut circle_points);
-    while _x < _y {
-        if d < 0 {
-            d = d + 2 * _x + 3;
-            _x += 1;
-        }else {
-            d = d + 2 * _x - 2 * _y + 5;
-            _x += 1;
-            _y -= 1;
+        candidates.extend(octant.iter().map(|point| {
+            let x = f64::from(point.x) - f64::from(circle.center.x);
+            let y = f64::from(point.y) - f64::from(circle.center.y);
+            (offset(y.atan2(x).to_degrees()), *point)
+        }));
+    }
+
+    let closest = |angle: f64| {
+        let distance = |offset: f64| {
+            let difference = (offset - angle).rem_euclid(360.0);
+            difference.min(360.0 - difference)
+        };
+        candidates
+            .iter()
+            .map(|(offset, _)| *offset)
+            .min_by(|a, b| distance(*a).partial_cmp(&distance(*b)).unwrap())
+    };
+    let (first, last) = match (closest(0.0), closest(sweep)) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return vec![],
+    };
+    // The closest pixels may lie just before the start or just after the end.
+    let first = if first > 180.0 { first - 360.0 } else { first };
+    let last = if last < sweep - 180.0 { last + 360.0 } else { last };
+
+    let mut arc: Vec<(f64, Point)> = vec![];
+    for (offset, point) in candidates {
+        for offset in [offset - 360.0, offset, offset + 360.0].iter().copied() {
+            // Only the end may come round to the first pixel again.
+            if offset >= first && offset <= last && (offset < first + 360.0 || offset == last) {
+                arc.push((offset, point));
+            }
         }
-        add_with_mirror_points(_x, _y, &mut circle_points);
     }
+    arc.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    arc.into_iter().map(|(_, point)| point).collect()
+}
+
+/// Draws the arc of the circle of `radius` around `center` from
+/// `start_angle` to `end_angle`, as in [`calc_arc_points`], onto `canvas`.
+/// The arc is clipped to the canvas pixel by pixel.
+pub fn draw_arc<C: Canvas>(
+    center: Point,
+    radius: i32,
+    start_angle: f64,
+    end_angle: f64,
+    color: Rgba<u8>,
+    canvas: &mut C,
+) {
+    for point in calc_arc_points(Circle::new(center, radius), start_angle, end_angle) {
+        canvas.put_world_pixel(point, color);
+    }
+}
 
-    for point in &mut circle_points {
-        point[0] += x;
-        point[1] += y;
+/// Draws the arc from `start_angle` to `end_angle` together with the radii
+/// to both of its ends, which close it into a pie slice.
+pub fn draw_pie<C: Canvas>(
+    center: Point,
+    radius: i32,
+    start_angle: f64,
+    end_angle: f64,
+    color: Rgba<u8>,
+    canvas: &mut C,
+) {
+    let arc = calc_arc_points(Circle::new(center, radius), start_angle, end_angle);
+    if let (Some(&first), Some(&last)) = (arc.first(), arc.last()) {
+        draw_line(Line::new(center, first), color, canvas);
+        draw_line(Line::new(center, last), color, canvas);
+    }
+    for point in arc {
+        canvas.put_world_pixel(point, color);
     }
+}
 
-    conv_actual_point(circle_points)
+/// Fills the disc of `circle` onto `canvas` through its viewport. The disc is
+/// clipped to the canvas, and only its rows on the canvas are calculated.
+pub fn fill_circle<C: Canvas>(circle: Circle, color: Rgba<u8>, canvas: &mut C) {
+    let bounds = canvas.world_bounds();
+
+    fill_spans(&calc_circle_spans_between(circle, bounds.min.y, bounds.max.y), color, canvas);
 }
 
-fn conv_actual_point(points: Vec<[i16;2]>) -> Vec<[u32;2]>{
-    let mut actual_points: Vec<[u32; 2]> = vec![];
+/// Draws a circle of `radius` around `center` together with eight circles of
+/// half the radius around it, which overlap into a flower. The circles are
+/// drawn as `outline` says.
+pub fn draw_flower<C: Canvas>(center: Point, radius: i32, outline: Outline, color: Rgba<u8>, canvas: &mut C) {
+    outline.draw_circle(Circle::new(center, radius), color, canvas);
+    draw_child_circles(center, radius, outline, color, canvas);
+}
 
-    for point in points {
-        let x = (point[0] + (WINDOW_SIZE / 2) as i16) as u32;
-        let y = (point[1].neg() + (WINDOW_SIZE / 2) as i16) as u32;
-        actual_points.push([x,y]);
+/// Draws the eight circles of the flower. Their centers lie half the radius
+/// away from `center`, turned by multiples of 45°.
+fn draw_child_circles<C: Canvas>(center: Point, radius: i32, outline: Outline, color: Rgba<u8>, canvas: &mut C) {
+    let first = Point::new(radius / 2, 0);
+    let to_center = Transform2D::translate(f64::from(center.x), f64::from(center.y));
+
+    for i in 0..8 {
+        let layout = Transform2D::rotate(f64::from(i) * 45.0).then(&to_center);
+        outline.draw_circle(Circle::new(layout.apply(first), radius / 2), color, canvas);
     }
+}
 
-    actual_points
-}
\ No newline at end of file
+/// Draws the horizontal, vertical and both diagonal axis through the world
+/// origin of `canvas`, which separate the eight octants of a circle. They are
+/// dashed with `pattern`, which starts at the origin on every ray.
+pub fn draw_8way_axis<C: Canvas>(pattern: &DashPattern, color: Rgba<u8>, canvas: &mut C) {
+    let origin = canvas.viewport().to_device(Point::new(0, 0));
+    let (x, y) = (i64::from(origin.x), i64::from(origin.y));
+    let size = i64::from(canvas.width().max(canvas.height()));
+    for i in (-size..size).filter(|i| pattern.is_on(i.unsigned_abs())) {
+        canvas.put_pixel_clipped(x + i, y, color);
+        canvas.put_pixel_clipped(x, y + i, color);
+        canvas.put_pixel_clipped(x + i, y + i, color);
+        canvas.put_pixel_clipped(x + i, y - i, color);
+    }
+}
@@ -0,0 +1,239 @@
+//! Ellipse drawing with the Mid-Point algorithm.
+//!
+//! Only the first quadrant is calculated, from the top of the ellipse down to
+//! its right end, in two regions: in region 1 the slope of the ellipse is
+//! above -1 and X steps every pixel, in region 2 it is below -1 and Y steps
+//! every pixel. The other three quadrants are filled in through the 4-way
+//! symmetry of the ellipse.
+
+use std::ops::Neg;
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Ellipse, Point, Span};
+use crate::midpoint_circle::{offset, spans_from_half_widths, split_coverage};
+use crate::polygon_fill::fill_spans;
+
+/// Adds the points of the first quadrant, in counter-clockwise order, to all
+/// four quadrants, by calling `add` with the quadrant, the index of the point
+/// in `points` and the point mirrored into the quadrant.
+///
+/// Neighbouring quadrants meet at the axis. Those points are only added to
+/// the quadrant that starts there when walking counter-clockwise.
+fn add_with_mirror_points<F: FnMut(usize, usize, [i64; 2])>(points: &[[i64; 2]], mut add: F) {
+    for (i, &[x, y]) in points.iter().enumerate() {
+        if x > 0 {
+            add(0, i, [x, y]);
+            add(2, i, [x.neg(), y.neg()]);
+        }
+    }
+    for (i, &[x, y]) in points.iter().enumerate().rev() {
+        if y > 0 {
+            add(1, i, [x.neg(), y]);
+            add(3, i, [x, y.neg()]);
+        }
+    }
+}
+
+/// Calculates the points of `ellipse`, once each, in order around the
+/// perimeter.
+///
+/// Like [`calc_circle_points`](crate::midpoint_circle::calc_circle_points),
+/// the points start at the rightmost point and go counter-clockwise, with
+/// the Y axis pointing up, and every point is a neighbour of the one before
+/// it. A flat ellipse, with one of the radii 0, is the segment
+/// between its ends. Points that would not fit in an `i32` are left
+/// out, and an ellipse with a negative radius has no points.
+pub fn calc_ellipse_points(ellipse: Ellipse) -> Vec<Point> {
+    let mut quadrants: [Vec<[i64; 2]>; 4] = Default::default();
+    let rx = i64::from(ellipse.rx);
+    let ry = i64::from(ellipse.ry);
+
+    if rx < 0 || ry < 0 {
+        return vec![];
+    } else if rx == 0 {
+        quadrants[0].extend((-ry..=ry).rev().map(|y| [0, y]));
+    } else if ry == 0 {
+        quadrants[0].extend((-rx..=rx).rev().map(|x| [x, 0]));
+    } else {
+        let mut first_quadrant = calc_first_quadrant(rx, ry);
+        first_quadrant.reverse();
+
+        add_with_mirror_points(&first_quadrant, |quadrant, _, point| quadrants[quadrant].push(point));
+    }
+
+    quadrants.iter().flatten().filter_map(|&point| offset(ellipse.center, point)).collect()
+}
+
+/// Calculates the pixels of the anti-aliased outline of `ellipse`, each with
+/// its coverage between 0 and 1, in the same order as
+/// [`calc_ellipse_points`].
+///
+/// In region 1 every column is split between the two pixels the exact
+/// ellipse passes between, in region 2 every row, and the other three
+/// quadrants are mirror images of the first one. A flat ellipse is its
+/// segment at full coverage. Pixels with no coverage and pixels that would
+/// not fit in an `i32` are left out, and an ellipse with a negative radius
+/// has none.
+pub fn calc_wu_ellipse_points(ellipse: Ellipse) -> Vec<(Point, f64)> {
+    let rx = i64::from(ellipse.rx);
+    let ry = i64::from(ellipse.ry);
+    if rx <= 0 || ry <= 0 {
+        return calc_ellipse_points(ellipse).into_iter().map(|point| (point, 1.0)).collect();
+    }
+
+    let (a, b) = (rx as f64, ry as f64);
+    // Where the slope of the ellipse is -1, between the two regions.
+    let diagonal = (a * a + b * b).sqrt();
+    let last_column = (a * a / diagonal).floor() as i64;
+    let last_row = (b * b / diagonal).floor() as i64;
+
+    // The pixels above the line from the center through the point between the
+    // regions belong to region 1, the others to region 2.
+    let (rx2, ry2) = (i128::from(rx * rx), i128::from(ry * ry));
+    let in_region_1 = |x: i64, y: i64| rx2 * i128::from(y) >= ry2 * i128::from(x);
+
+    // Walked clockwise from the top, first column by column and then row by
+    // row.
+    let mut points = vec![];
+    let mut coverages = vec![];
+    for x in 0..=last_column {
+        let exact_y = b * (1.0 - (x as f64 / a).powi(2)).max(0.0).sqrt();
+        for (y, coverage) in split_coverage(exact_y) {
+            if coverage > 0.0 && in_region_1(x, y) {
+                points.push([x, y]);
+                coverages.push(coverage);
+            }
+        }
+    }
+    for y in (0..=last_row).rev() {
+        let exact_x = a * (1.0 - (y as f64 / b).powi(2)).max(0.0).sqrt();
+        for (x, coverage) in split_coverage(exact_x) {
+            if coverage > 0.0 && !in_region_1(x, y) {
+                points.push([x, y]);
+                coverages.push(coverage);
+            }
+        }
+    }
+    points.reverse();
+    coverages.reverse();
+
+    let mut quadrants: [Vec<([i64; 2], f64)>; 4] = Default::default();
+    add_with_mirror_points(&points, |quadrant, i, point| quadrants[quadrant].push((point, coverages[i])));
+
+    quadrants
+        .iter()
+        .flatten()
+        .filter_map(|&(point, coverage)| Some((offset(ellipse.center, point)?, coverage)))
+        .collect()
+}
+
+/// Calculates the spans that fill `ellipse`, from the bottom row up.
+///
+/// Every row of the ellipse is one span, reaching out to the outline drawn
+/// by [`calc_ellipse_points`], so every pixel is covered exactly once. Like
+/// [`calc_circle_spans`](crate::midpoint_circle::calc_circle_spans), rows that
+/// would not fit in an `i32` are left out and the spans are cut off at the
+/// `i32` limits. An ellipse with a negative radius has no spans.
+pub fn calc_ellipse_spans(ellipse: Ellipse) -> Vec<Span> {
+    calc_ellipse_spans_between(ellipse, i64::from(i32::MIN), i64::from(i32::MAX))
+}
+
+/// Calculates the spans of [`calc_ellipse_spans`] for the rows from `y_min`
+/// to `y_max` only.
+fn calc_ellipse_spans_between(ellipse: Ellipse, y_min: i64, y_max: i64) -> Vec<Span> {
+    let rx = i64::from(ellipse.rx);
+    let ry = i64::from(ellipse.ry);
+    if rx < 0 || ry < 0 {
+        return vec![];
+    }
+
+    // The half width of the ellipse for every row above the center.
+    let mut half_widths = vec![0; ry as usize + 1];
+    if rx > 0 && ry == 0 {
+        half_widths[0] = rx;
+    } else if rx > 0 {
+        for [x, y] in calc_first_quadrant(rx, ry) {
+            half_widths[y as usize] = half_widths[y as usize].max(x);
+        }
+    }
+
+    spans_from_half_widths(ellipse.center, ry, y_min, y_max, |dy| half_widths[dy as usize])
+}
+
+/// Walks the first quadrant from (0, `ry`) to (`rx`, 0).
+fn calc_first_quadrant(rx: i64, ry: i64) -> Vec<[i64; 2]> {
+    // The decision variables are scaled by 4 to stay integers, which needs
+    // more than 64 bits for radii close to the `i32` limits.
+    let rx2 = i128::from(rx * rx);
+    let ry2 = i128::from(ry * ry);
+    let mut points = vec![];
+
+    let mut _x = 0;
+    let mut _y = ry;
+    let mut d1 = 4 * ry2 - 4 * rx2 * i128::from(ry) + rx2;
+
+    // Region 1, while 2 * ry² * x < 2 * rx² * y.
+    while ry2 * i128::from(_x) < rx2 * i128::from(_y) {
+        points.push([_x, _y]);
+        _x += 1;
+        if d1 < 0 {
+            d1 += 4 * (2 * ry2 * i128::from(_x) + ry2);
+        } else {
+            _y -= 1;
+            d1 += 4 * (2 * ry2 * i128::from(_x) - 2 * rx2 * i128::from(_y) + ry2);
+        }
+    }
+
+    // Region 2, down to the X axis.
+    let mut d2 = ry2 * i128::from(2 * _x + 1).pow(2) + 4 * rx2 * i128::from(_y - 1).pow(2) - 4 * rx2 * ry2;
+    while _y >= 0 {
+        points.push([_x, _y]);
+        _y -= 1;
+        if d2 > 0 {
+            d2 += 4 * (rx2 - 2 * rx2 * i128::from(_y));
+        } else {
+            _x += 1;
+            d2 += 4 * (2 * ry2 * i128::from(_x) - 2 * rx2 * i128::from(_y) + rx2);
+        }
+    }
+
+    // Very flat ellipses reach the X axis before their right end. The row
+    // above the axis is drawn out to the end instead, as a row on the axis
+    // would be walked back along on the other side of it.
+    let last_x = points.last().map_or(0, |point| point[0]);
+    if last_x < rx {
+        points.pop();
+        let row_end = points.last().map_or(0, |point| point[0]);
+        points.extend((row_end + 1..rx).map(|x| [x, 1]));
+        points.push([rx, 0]);
+    }
+
+    points
+}
+
+/// Draws `ellipse` onto `canvas` through its viewport. The ellipse is clipped
+/// to the canvas pixel by pixel.
+pub fn draw_ellipse<C: Canvas>(ellipse: Ellipse, color: Rgba<u8>, canvas: &mut C) {
+    for point in calc_ellipse_points(ellipse) {
+        canvas.put_world_pixel(point, color);
+    }
+}
+
+/// Blends the anti-aliased outline of `ellipse` onto `canvas` through its
+/// viewport, with the coverage of each pixel as its opacity.
+pub fn draw_ellipse_wu<C: Canvas>(ellipse: Ellipse, color: Rgba<u8>, canvas: &mut C) {
+    for (point, coverage) in calc_wu_ellipse_points(ellipse) {
+        canvas.blend_world_pixel(point, color, coverage);
+    }
+}
+
+/// Fills `ellipse` onto `canvas` through its viewport. The ellipse is clipped
+/// to the canvas, and only its rows on the canvas are turned into spans.
+pub fn fill_ellipse<C: Canvas>(ellipse: Ellipse, color: Rgba<u8>, canvas: &mut C) {
+    let bounds = canvas.world_bounds();
+    let (y_min, y_max) = (i64::from(bounds.min.y), i64::from(bounds.max.y));
+
+    fill_spans(&calc_ellipse_spans_between(ellipse, y_min, y_max), color, canvas);
+}
@@ -1,352 +1,247 @@
-extern crate image;
-extern crate piston_window;
-
-use std::env;
-use std::ops::Neg;
-
-use image::{ImageBuffer, Rgba};
-use log::{info, LevelFilter, trace, warn};
-use piston_window::*;
-use simplelog::{Config, TerminalMode, TermLogger};
-
-static WINDOW_SIZE: u32 = 800;
-
-fn main() {
-    let mut window: PistonWindow = WindowSettings::new("Mid-Point Line", [WINDOW_SIZE; 2])
-        .exit_on_esc(true)
-        .graphics_api(OpenGL::V4_5)
-        .resizable(false)
-        .build()
-        .unwrap();
-
-    TermLogger::init(LevelFilter::Trace, Config::default(), TerminalMode::Mixed).unwrap();
-
-    let args: Vec<String> = env::args().collect();
-    info!("Running Program from {}", args[0]);
-
-
-    let line: Line;
-
-    if args.len() < 5 {
-        warn!("Not Enough Argument. Using default values.");
-
-        // Default
-        let point_a = Point::from(2, 63);
-        let point_b = Point::from(252, 242);
-
-        // Test Case 1
-        // let point_a = Point::from(10, 10);
-        // let point_b = Point::from(60, 50);
+//! Line drawing with the Mid-Point algorithm.
+//!
+//! The algorithm itself only handles lines in zone 0 (`0 <= Δy <= Δx`). Lines in
+//! any other zone are converted to zone 0 with [`Line::convert_to_zone_zero`],
+//! rasterized with [`calc_line_midpoint`] and converted back with
+//! [`convert_zone`]. [`calc_line_points`] does all three steps.
+
+use image::Rgba;
+use log::trace;
+
+use crate::canvas::Canvas;
+use crate::dash::DashPattern;
+use crate::geometry::{Line, Point};
+use crate::transform::Transform2D;
+
+/// One of the eight octants a line can point into.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    ZERO,
+    ONE,
+    TWO,
+    THREE,
+    FOUR,
+    FIVE,
+    SIX,
+    SEVEN,
+}
 
-        // Test Case 2
-        // let point_a = Point::from(10, -10);
-        // let point_b = Point::from(60, -50);
+impl Zone {
+    pub fn name(&self) -> u8 {
+        match self {
+            Zone::ZERO => 0,
+            Zone::ONE => 1,
+            Zone::TWO => 2,
+            Zone::THREE => 3,
+            Zone::FOUR => 4,
+            Zone::FIVE => 5,
+            Zone::SIX => 6,
+            Zone::SEVEN => 7,
+        }
+    }
 
-        // Test Case 3
-        // let point_a = Point::from(-30, -10);
-        // let point_b = Point::from(-100, -40);
+    /// The transformation that mirrors or turns lines of this zone into zone
+    /// 0, so they can be passed to [`calc_line_midpoint`].
+    pub fn to_zone_zero(&self) -> Transform2D {
+        transform_of(&self.matrix_to_zone_zero())
+    }
 
-        line = Line::from(point_a, point_b);
-    } else {
-        let x1 = args[1].parse::<i32>().expect("Could Not Parse X1");
-        let y1 = args[2].parse::<i32>().expect("Could Not Parse Y1");
-        let x2 = args[3].parse::<i32>().expect("Could Not Parse X2");
-        let y2 = args[4].parse::<i32>().expect("Could Not Parse Y2");
+    /// The transformation that takes points calculated in zone 0 back into
+    /// this zone.
+    pub fn from_zone_zero(&self) -> Transform2D {
+        transform_of(&self.matrix_from_zone_zero())
+    }
 
-        let point_a = Point { x: x1, y: y1 };
-        let point_b = Point { x: x2, y: y2 };
-        line = Line::from(point_a, point_b);
+    fn matrix_to_zone_zero(&self) -> ZoneMatrix {
+        TO_ZONE_ZERO[usize::from(self.name())]
     }
 
-    let mut canvas = image::ImageBuffer::new(WINDOW_SIZE, WINDOW_SIZE);
+    /// The inverse of a mirror or a turn by a multiple of 90° is its
+    /// transpose.
+    fn matrix_from_zone_zero(&self) -> ZoneMatrix {
+        let [[a, b], [c, d]] = self.matrix_to_zone_zero();
+        [[a, c], [b, d]]
+    }
+}
 
-    let mut texture_context = TextureContext {
-        factory: window.factory.clone(),
-        encoder: window.factory.create_command_buffer().into(),
+/// A 2×2 matrix of 0, 1 and -1 that takes (x, y) to (`m[0][0]` x + `m[0][1]`
+/// y, `m[1][0]` x + `m[1][1]` y). Every row has one entry that is not 0, so
+/// the zones are changed by swapping and negating coordinates.
+type ZoneMatrix = [[i32; 2]; 2];
+
+/// The matrices that take each zone into zone 0, by the name of the zone:
+/// mirrors at 45°, 90°, -45° and 0° for the odd zones and turns by -90°,
+/// 180° and 90° for the even ones.
+const TO_ZONE_ZERO: [ZoneMatrix; 8] = [
+    [[1, 0], [0, 1]],
+    [[0, 1], [1, 0]],
+    [[0, 1], [-1, 0]],
+    [[-1, 0], [0, 1]],
+    [[-1, 0], [0, -1]],
+    [[0, -1], [-1, 0]],
+    [[0, -1], [1, 0]],
+    [[1, 0], [0, -1]],
+];
+
+/// Moves `point` with a zone matrix. Negated coordinates are cut off at the
+/// `i32` limits, like in [`Transform2D::apply`].
+fn apply_zone_matrix(matrix: &ZoneMatrix, point: Point) -> Point {
+    let term = |factor: i32, value: i32| match factor {
+        1 => value,
+        -1 => value.saturating_neg(),
+        _ => 0,
     };
+    let row = |[a, b]: [i32; 2]| term(a, point.x) + term(b, point.y);
+    Point::new(row(matrix[0]), row(matrix[1]))
+}
 
-    let mut texture: G2dTexture =
-        Texture::from_image(&mut texture_context, &canvas, &TextureSettings::new()).unwrap();
-
-
-    draw_center_axis(&mut canvas);
-    draw_line(line, &mut canvas);
-
-
-    texture.update(&mut texture_context, &canvas).unwrap();
-
-    while let Some(event) = window.next() {
-        window.draw_2d(&event, |context, graphics, device| {
-            // Update texture before rendering.
-            texture_context.encoder.flush(device);
-
+fn transform_of(matrix: &ZoneMatrix) -> Transform2D {
+    let [[a, b], [c, d]] = *matrix;
+    Transform2D::new([
+        [f64::from(a), f64::from(b), 0.0],
+        [f64::from(c), f64::from(d), 0.0],
+        [0.0, 0.0, 1.0],
+    ])
+}
 
-            image(&texture, context.transform, graphics);
-        });
+impl Line {
+    /// The zone the line points into, when walked from `start` to `end`.
+    pub fn zone(&self) -> Zone {
+        let zone = Self::find_zone(&self.start, &self.end);
+        trace!("Zone: {}", zone.name());
+        zone
     }
 
-    fn draw_center_axis(canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-        for i in 0..WINDOW_SIZE {
-            canvas.put_pixel(i, WINDOW_SIZE / 2, Rgba([255, 0, 0, 255]));
-            canvas.put_pixel(WINDOW_SIZE / 2, i, Rgba([255, 0, 0, 255]));
+    fn find_zone(start: &Point, end: &Point) -> Zone {
+        trace!("X1: {} Y1: {}", start.x, start.y);
+        trace!("X2: {} Y2: {}", end.x, end.y);
+
+        // The difference of two i32 may not fit into an i32.
+        let delta_x = i64::from(end.x) - i64::from(start.x);
+        let delta_y = i64::from(end.y) - i64::from(start.y);
+
+        trace!("ΔX: {}", delta_x);
+        trace!("ΔY: {}", delta_y);
+
+        // Zone n covers the directions after n * 45° up to and including
+        // (n + 1) * 45°, so every line converted to zone 0 stays in zone 0.
+        // Zone 0 also takes 0° itself and lines of zero length.
+        if delta_x >= 0 && delta_y >= 0 && delta_y <= delta_x {
+            Zone::ZERO
+        } else if delta_x >= 0 && delta_y > delta_x {
+            Zone::ONE
+        } else if delta_y > 0 && delta_y >= delta_x.abs() {
+            Zone::TWO
+        } else if delta_y >= 0 {
+            Zone::THREE
+        } else if delta_x < 0 && delta_y.abs() <= delta_x.abs() {
+            Zone::FOUR
+        } else if delta_x <= 0 {
+            Zone::FIVE
+        } else if delta_y.abs() >= delta_x {
+            Zone::SIX
+        } else {
+            Zone::SEVEN
         }
     }
 
-    fn draw_line(line: Line, canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-        let zero_line = line.convert_to_zone_zero();
-        let mut line_points = calc_line_midpoint(zero_line);
-        convert_zone(&mut line_points, &line.zone);
+    /// Mirrors the line into zone 0 so it can be passed to [`calc_line_midpoint`].
+    pub fn convert_to_zone_zero(&self) -> Line {
+        trace!("Converting to Zone 0");
+        let matrix = self.zone().matrix_to_zone_zero();
+        let (start, end) = (apply_zone_matrix(&matrix, self.start), apply_zone_matrix(&matrix, self.end));
 
-        trace!("--------- Zone : {} ----------", &line.zone.name());
-        for point in &line_points {
-            let pointer = Point::from(point[0], point[1]);
-            trace!("x:{} y:{}", pointer.x, pointer.y);
-            draw_point(pointer.actual_x(), pointer.actual_y(), canvas);
-        }
-    }
+        trace!("X1': {} Y1': {}", start.x, start.y);
+        trace!("X2': {} Y2': {}", end.x, end.y);
 
-    fn draw_point(x: u32, y: u32, canvas: &mut ImageBuffer<Rgba<u8>, Vec<u8>>) {
-        canvas.put_pixel(x, y, Rgba([0, 255, 0, 255]));
+        Line { start, end }
     }
+}
 
-    fn calc_line_midpoint(zero_line: Line) -> Vec<[i32; 2]> {
-        let delta_x = zero_line.end.x - zero_line.start.x;
-        let delta_y = zero_line.end.y - zero_line.start.y;
-        let mut d = 2 * delta_y - delta_x;
-        let delta_ne = 2 * (delta_y - delta_x);
-        let delta_e = 2 * delta_y;
-
-        trace!("ΔX': {}", delta_x);
-        trace!("ΔY': {}", delta_y);
-        trace!("D: {}", d);
-        trace!("ΔNE: {}", delta_ne);
-        trace!("ΔE: {}", delta_e);
-
-        let mut points: Vec<[i32; 2]> = vec![];
-
-        let mut x = zero_line.start.x;
-        let mut y = zero_line.start.y;
-        while x <= zero_line.end.x {
-            points.push([x, y]);
-            x += 1;
-
-            if d > 0 {
-                y += 1;
-                d += delta_ne;
-
-                let delta_type;
-                if d < 0 {
-                    delta_type = "deltaE";
-                }else {
-                    delta_type = "deltaNE";
-                }
-
-                trace!("x:{} y:{} {}:{}", x, y, delta_type, d);
-            } else {
-                d += delta_e;
-
-                let delta_type;
-                if d < 0 {
-                    delta_type = "deltaE";
-                }else {
-                    delta_type = "deltaNE";
-                }
-
-                trace!("x:{} y:{} {}:{}", x, y, delta_type, d);
-            }
-        }
-
-        // trace!("--- ZONE 0 Points ---");
-        // for point in &points {
-        //     trace!("x:{} y:{}", point[0], point[1]);
-        // }
-        points
-    }
+/// Calculates the pixels of `line`, whatever zone it lies in.
+pub fn calc_line_points(line: Line) -> Vec<Point> {
+    let zone = line.zone();
+    let mut points = calc_line_midpoint(line.convert_to_zone_zero());
+    convert_zone(&mut points, &zone);
+    points
+}
 
-    enum Zone {
-        ZERO,
-        ONE,
-        TWO,
-        THREE,
-        FOUR,
-        FIVE,
-        SIX,
-        SEVEN,
+/// Draws `line` onto `canvas` through its viewport. Pixels outside of the
+/// canvas are skipped.
+pub fn draw_line<C: Canvas>(line: Line, color: Rgba<u8>, canvas: &mut C) {
+    trace!("--------- Zone : {} ----------", line.zone().name());
+    for point in calc_line_points(line) {
+        trace!("x:{} y:{}", point.x, point.y);
+        canvas.put_world_pixel(point, color);
     }
+}
 
-    impl Zone {
-        pub fn name(&self) -> u8 {
-            match self {
-                Zone::ZERO => 0,
-                Zone::ONE => 1,
-                Zone::TWO => 2,
-                Zone::THREE => 3,
-                Zone::FOUR => 4,
-                Zone::FIVE => 5,
-                Zone::SIX => 6,
-                Zone::SEVEN => 7,
-            }
+/// Draws the X and Y axis through the world origin of `canvas`, dashed with
+/// `pattern`. The pattern starts at the origin on all four sides of it.
+pub fn draw_center_axis<C: Canvas>(pattern: &DashPattern, color: Rgba<u8>, canvas: &mut C) {
+    let origin = canvas.viewport().to_device(Point::new(0, 0));
+    let (x, y) = (i64::from(origin.x), i64::from(origin.y));
+    for i in 0..i64::from(canvas.width()) {
+        if pattern.is_on((i - x).unsigned_abs()) {
+            canvas.put_pixel_clipped(i, y, color);
         }
     }
-
-    struct Line {
-        start: Point,
-        end: Point,
-        zone: Zone,
-    }
-
-    impl Line {
-        pub fn from(starting_point: Point, ending_point: Point) -> Self {
-            let line = Self {
-                zone: Self::find_zone(&starting_point, &ending_point),
-                start: starting_point,
-                end: ending_point,
-            };
-            trace!("Zone: {}", line.zone.name());
-            line
-        }
-
-        fn find_zone(start: &Point, end: &Point) -> Zone {
-            trace!("X1: {} Y1: {}", start.x, start.y);
-            trace!("X2: {} Y2: {}", end.x, end.y);
-
-            let delta_x = end.x - start.x;
-            let delta_y = end.y - start.y;
-
-            trace!("ΔX: {}", delta_x);
-            trace!("ΔY: {}", delta_y);
-
-
-            if delta_x > 0 && delta_y > 0 && delta_x.abs() > delta_y.abs() {
-                Zone::ZERO
-            } else if delta_x > 0 && delta_y > 0 && delta_y.abs() > delta_x.abs() {
-                Zone::ONE
-            } else if delta_x < 0 && delta_y > 0 && delta_y.abs() > delta_x.abs() {
-                Zone::TWO
-            } else if delta_x < 0 && delta_y > 0 && delta_y.abs() < delta_x.abs() {
-                Zone::THREE
-            } else if delta_x < 0 && delta_y < 0 && delta_x.abs() > delta_y.abs() {
-                Zone::FOUR
-            } else if delta_x < 0 && delta_y < 0 && delta_x.abs() < delta_y.abs() {
-                Zone::FIVE
-            } else if delta_x > 0 && delta_y < 0 && delta_x.abs() < delta_y.abs() {
-                Zone::SIX
-            } else {
-                Zone::SEVEN
-            }
-        }
-
-        pub fn convert_to_zone_zero(&self) -> Line {
-            let start: Point;
-            let end: Point;
-
-            trace!("Converting to Zone 0");
-            match self.zone {
-                Zone::ZERO => {
-                    start = Point { x: self.start.x, y: self.start.y };
-                    end = Point { x: self.end.x, y: self.end.y };
-                }
-                Zone::ONE => {
-                    start = Point { x: self.start.y, y: self.start.x };
-                    end = Point { x: self.end.y, y: self.end.x };
-                }
-                Zone::TWO => {
-                    start = Point { x: self.start.y, y: self.start.x.neg() };
-                    end = Point { x: self.end.y, y: self.end.x.neg() };
-                }
-                Zone::THREE => {
-                    start = Point { x: self.start.x.neg(), y: self.start.y };
-                    end = Point { x: self.end.x.neg(), y: self.end.y };
-                }
-                Zone::FOUR => {
-                    start = Point { x: self.start.x.neg(), y: self.start.y.neg() };
-                    end = Point { x: self.end.x.neg(), y: self.end.y.neg() };
-                }
-                Zone::FIVE => {
-                    start = Point { x: self.start.y.neg(), y: self.start.x.neg() };
-                    end = Point { x: self.end.y.neg(), y: self.end.x.neg() };
-                }
-                Zone::SIX => {
-                    start = Point { x: self.start.y.neg(), y: self.start.x };
-                    end = Point { x: self.end.y.neg(), y: self.end.x };
-                }
-                Zone::SEVEN => {
-                    start = Point { x: self.start.x, y: self.start.y.neg() };
-                    end = Point { x: self.end.x, y: self.end.y.neg() };
-                }
-            }
-
-            trace!("X1': {} Y1': {}", start.x, start.y);
-            trace!("X2': {} Y2': {}", end.x, end.y);
-
-            Line { start, end, zone: Zone::ZERO }
+    for i in 0..i64::from(canvas.height()) {
+        if pattern.is_on((i - y).unsigned_abs()) {
+            canvas.put_pixel_clipped(x, i, color);
         }
     }
+}
 
-    impl Point {
-        pub fn from(x: i32, y: i32) -> Self {
-            Self { x, y }
-        }
-
-        pub fn actual_x(&self) -> u32 {
-            (self.x + (self::WINDOW_SIZE / 2) as i32) as u32
+/// Calculates the pixels of a line that lies in zone 0.
+///
+/// The decision variable is kept in an i64, so any two points of `i32`
+/// coordinates work.
+pub fn calc_line_midpoint(zero_line: Line) -> Vec<Point> {
+    let delta_x = i64::from(zero_line.end.x) - i64::from(zero_line.start.x);
+    let delta_y = i64::from(zero_line.end.y) - i64::from(zero_line.start.y);
+    let mut d = 2 * delta_y - delta_x;
+    let delta_ne = 2 * (delta_y - delta_x);
+    let delta_e = 2 * delta_y;
+
+    trace!("ΔX': {}", delta_x);
+    trace!("ΔY': {}", delta_y);
+    trace!("D: {}", d);
+    trace!("ΔNE: {}", delta_ne);
+    trace!("ΔE: {}", delta_e);
+
+    let mut points: Vec<Point> = vec![];
+
+    // Walked in i64 too, as the step past the end may not fit into an i32.
+    let mut x = i64::from(zero_line.start.x);
+    let mut y = i64::from(zero_line.start.y);
+    while x <= i64::from(zero_line.end.x) {
+        points.push(Point::new(x as i32, y as i32));
+        x += 1;
+
+        if d > 0 {
+            y += 1;
+            d += delta_ne;
+        } else {
+            d += delta_e;
         }
 
-        pub fn actual_y(&self) -> u32 {
-            (self.y.neg() + (self::WINDOW_SIZE / 2) as i32) as u32
-        }
+        let delta_type = if d < 0 { "deltaE" } else { "deltaNE" };
+        trace!("x:{} y:{} {}:{}", x, y, delta_type, d);
     }
 
-    struct Point {
-        x: i32,
-        y: i32,
+    points
+}
+
+/// Mirrors points calculated in zone 0 back into `zone`.
+pub fn convert_zone(points: &mut [Point], zone: &Zone) {
+    if *zone == Zone::ZERO {
+        return;
     }
 
-    fn convert_zone(points: &mut Vec<[i32; 2]>, zone: &Zone) {
-        match zone {
-            Zone::ZERO => {}
-            Zone::ONE => {
-                for point in points {
-                    point.swap(0, 1);
-                }
-            }
-            Zone::TWO => {
-                for point in points {
-                    let new_x = point[1].neg();
-                    point[1] = point[0];
-                    point[0] = new_x;
-                }
-            }
-            Zone::THREE => {
-                for point in points {
-                    point[0] = point[0].neg();
-                }
-            }
-            Zone::FOUR => {
-                for point in points {
-                    point[0] = point[0].neg();
-                    point[1] = point[1].neg();
-                }
-            }
-            Zone::FIVE => {
-                for point in points {
-                    let new_x = point[1].neg();
-                    point[1] = point[0].neg();
-                    point[0] = new_x;
-                }
-            }
-            Zone::SIX => {
-                for point in points {
-                    let new_x = point[1];
-                    point[1] = point[0].neg();
-                    point[0] = new_x;
-                }
-            }
-            Zone::SEVEN => {
-                for point in points {
-                    point[1] = point[1].neg();
-                }
-            }
-        }
+    let matrix = zone.matrix_from_zone_zero();
+    for point in points {
+        *point = apply_zone_matrix(&matrix, *point);
     }
 }
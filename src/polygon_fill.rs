@@ -0,0 +1,175 @@
+//! Polygon filling with the scanline algorithm.
+//!
+//! The edges of the polygon are sorted into an edge table by their lowest
+//! row. Walking up the rows, edges move from the edge table into the active
+//! edge list once the row reaches them and leave it after their highest row.
+//! The crossings of the active edges with a row, sorted by X, bound the spans
+//! of that row that lie inside the polygon.
+//!
+//! A pixel is filled when its center is inside the polygon. Pixel centers on
+//! a left or bottom edge count as inside and those on a right or top edge do
+//! not, so polygons sharing an edge never fill the same pixel twice and the
+//! square from (0, 0) to (10, 10) fills exactly 100 pixels.
+
+use std::convert::TryFrom;
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Point, Polygon, Span};
+
+/// Decides which parts of a self-intersecting polygon are inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside when a ray from it crosses the edges an odd number
+    /// of times.
+    EvenOdd,
+    /// A point is inside when the edges wind around it at least once, in
+    /// either direction.
+    NonZero,
+}
+
+impl FillRule {
+    fn is_inside(&self, winding: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+/// An edge of the polygon, covering the rows from its lower end up to but
+/// not including its upper end.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    y_min: i64,
+    y_max: i64,
+    /// The X of the edge on the current row is `x_numerator / delta_y`.
+    x_numerator: i128,
+    delta_x: i128,
+    delta_y: i128,
+    /// 1 for edges going up, -1 for edges going down.
+    winding: i32,
+}
+
+impl Edge {
+    /// Returns `None` for horizontal edges, which never cross a row.
+    fn new(start: Point, end: Point) -> Option<Edge> {
+        if start.y == end.y {
+            return None;
+        }
+        let (low, high, winding) = if start.y < end.y { (start, end, 1) } else { (end, start, -1) };
+
+        let delta_y = i128::from(high.y) - i128::from(low.y);
+        Some(Edge {
+            y_min: i64::from(low.y),
+            y_max: i64::from(high.y),
+            x_numerator: i128::from(low.x) * delta_y,
+            delta_x: i128::from(high.x) - i128::from(low.x),
+            delta_y,
+            winding,
+        })
+    }
+
+    /// Moves the edge `rows` rows up.
+    fn step(&mut self, rows: i64) {
+        self.x_numerator += self.delta_x * i128::from(rows);
+    }
+
+    /// The first pixel column whose center is not left of the edge.
+    fn x_ceil(&self) -> i64 {
+        let floor = self.x_numerator.div_euclid(self.delta_y);
+        let ceil = if self.x_numerator.rem_euclid(self.delta_y) == 0 { floor } else { floor + 1 };
+        ceil as i64
+    }
+}
+
+/// Calculates the spans that fill `polygon` under `rule`, row by row from
+/// the bottom up and left to right within a row.
+pub fn calc_polygon_spans(polygon: &Polygon, rule: FillRule) -> Vec<Span> {
+    scan(polygon, rule, i64::from(i32::MIN), i64::from(i32::MAX))
+}
+
+/// Fills `polygon` onto `canvas` under `rule` through its viewport. Only the
+/// rows and columns on the canvas are walked.
+pub fn fill_polygon<C: Canvas>(polygon: &Polygon, rule: FillRule, color: Rgba<u8>, canvas: &mut C) {
+    let bounds = canvas.world_bounds();
+
+    fill_spans(&scan(polygon, rule, i64::from(bounds.min.y), i64::from(bounds.max.y)), color, canvas);
+}
+
+/// Fills `spans` onto `canvas` through its viewport. The spans are clipped to
+/// the canvas.
+pub fn fill_spans<C: Canvas>(spans: &[Span], color: Rgba<u8>, canvas: &mut C) {
+    let viewport = canvas.viewport();
+    let (width, height) = (i64::from(canvas.width()), i64::from(canvas.height()));
+    for span in spans {
+        // The device pixels of a span lie between those of its end pixels,
+        // which may be mirrored.
+        let (x_a, y_range) = viewport.device_pixels(Point::new(span.x_start, span.y));
+        let (x_b, _) = viewport.device_pixels(Point::new(span.x_end, span.y));
+        let x_start = x_a[0].min(x_b[0]).max(0);
+        let x_end = x_a[1].max(x_b[1]).min(width);
+        for y in y_range[0].max(0)..y_range[1].min(height) {
+            for x in x_start..x_end {
+                canvas.put_pixel(x as u32, y as u32, color);
+            }
+        }
+    }
+}
+
+/// Runs the scanline algorithm over the rows from `y_from` to `y_to`, both
+/// included.
+fn scan(polygon: &Polygon, rule: FillRule, y_from: i64, y_to: i64) -> Vec<Span> {
+    let mut edge_table: Vec<Edge> = polygon.edges().filter_map(|line| Edge::new(line.start, line.end)).collect();
+    edge_table.retain(|edge| edge.y_max > y_from && edge.y_min <= y_to);
+    // Sorted so the next edge to become active is at the end.
+    edge_table.sort_by_key(|edge| std::cmp::Reverse(edge.y_min));
+
+    let mut spans = vec![];
+    let mut active: Vec<Edge> = vec![];
+    let mut y = match edge_table.last() {
+        Some(edge) => edge.y_min.max(y_from),
+        None => return spans,
+    };
+
+    while y <= y_to && !(active.is_empty() && edge_table.is_empty()) {
+        if active.is_empty() {
+            // Skip the rows between parts of the polygon.
+            y = y.max(edge_table.last().unwrap().y_min);
+        }
+        while edge_table.last().is_some_and(|edge| edge.y_min <= y) {
+            let mut edge = edge_table.pop().unwrap();
+            edge.step(y - edge.y_min);
+            active.push(edge);
+        }
+        active.sort_by_key(|edge| edge.x_ceil());
+
+        let mut winding = 0;
+        let mut span_start = 0;
+        for edge in &active {
+            let was_inside = rule.is_inside(winding);
+            winding += edge.winding;
+            let is_inside = rule.is_inside(winding);
+
+            if !was_inside && is_inside {
+                span_start = edge.x_ceil();
+            } else if was_inside && !is_inside {
+                let span_end = edge.x_ceil() - 1;
+                if span_end >= span_start {
+                    // The crossings lie on edges between `i32` vertices.
+                    let to_i32 = |value: i64| i32::try_from(value).unwrap();
+                    spans.push(Span::new(to_i32(y), to_i32(span_start), to_i32(span_end)));
+                }
+            }
+        }
+
+        y += 1;
+        active.retain(|edge| edge.y_max > y);
+        for edge in &mut active {
+            edge.step(1);
+        }
+    }
+
+    spans
+}
@@ -0,0 +1,143 @@
+//! Rectangles with the Mid-Point line, optionally with rounded corners.
+//!
+//! The sides are Mid-Point lines between the corners. A rounded corner is the
+//! quarter of a Mid-Point circle around the point `radius` inside of both
+//! sides, cut out with [`calc_arc_points`], and the sides are shortened to
+//! meet the ends of the arcs.
+//!
+//! A border is what is left of the filled rectangle after the rectangle
+//! inside of the border is taken out of it. It lies within the rectangle, so
+//! the rectangle keeps its size whatever the width of its border.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Circle, Line, Point, Rect, Span};
+use crate::midpoint_circle::calc_arc_points;
+use crate::midpoint_line::calc_line_points;
+use crate::polygon_fill::fill_spans;
+
+/// Whether a rectangle is drawn as its outline, as a border or filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Only the pixels of the sides and the rounded corners.
+    Outline,
+    /// A border of the given width in pixels, from the outline inwards.
+    Border(u32),
+    /// Every pixel within the outline, the outline included.
+    Filled,
+}
+
+/// Calculates the points of the outline of `rect`, with its corners rounded
+/// off by `radius`, in order counter-clockwise from the bottom left.
+///
+/// A radius of 0 gives square corners. The radius is limited to half of the
+/// shorter side, which turns a square into a circle. Every pixel is only
+/// in the outline once, also where the sides meet the arcs.
+pub fn calc_rect_points(rect: Rect, radius: i32) -> Vec<Point> {
+    let radius = radius.clamp(0, rect.width().min(rect.height()) / 2);
+    let Rect { min, max } = rect;
+
+    // The centers of the rounded corners, counter-clockwise from the bottom
+    // right, each with the angle its arc starts at.
+    let corners = [
+        (Point::new(max.x - radius, min.y + radius), 270.0),
+        (Point::new(max.x - radius, max.y - radius), 0.0),
+        (Point::new(min.x + radius, max.y - radius), 90.0),
+        (Point::new(min.x + radius, min.y + radius), 180.0),
+    ];
+    let sides = [
+        Line::new(Point::new(min.x + radius, min.y), Point::new(max.x - radius, min.y)),
+        Line::new(Point::new(max.x, min.y + radius), Point::new(max.x, max.y - radius)),
+        Line::new(Point::new(max.x - radius, max.y), Point::new(min.x + radius, max.y)),
+        Line::new(Point::new(min.x, max.y - radius), Point::new(min.x, min.y + radius)),
+    ];
+
+    let mut points: Vec<Point> = vec![];
+    let mut add = |piece: Vec<Point>| {
+        for point in piece {
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+    };
+    for (side, &(center, start)) in sides.iter().zip(corners.iter()) {
+        add(calc_line_points(*side));
+        if radius > 0 {
+            add(calc_arc_points(Circle::new(center, radius), start, start + 90.0));
+        }
+    }
+    // The last arc ends where the first side starts.
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    points
+}
+
+/// Calculates the spans of `rect` filled, with its corners rounded off by
+/// `radius` like in [`calc_rect_points`], one per row from the bottom up.
+///
+/// Every span reaches out to the leftmost and rightmost pixel of the outline
+/// on its row, so the filled rectangle covers its outline exactly.
+pub fn calc_rect_spans(rect: Rect, radius: i32) -> Vec<Span> {
+    let mut rows: BTreeMap<i32, (i32, i32)> = BTreeMap::new();
+    for point in calc_rect_points(rect, radius) {
+        let row = rows.entry(point.y).or_insert((point.x, point.x));
+        *row = (row.0.min(point.x), row.1.max(point.x));
+    }
+    rows.into_iter().map(|(y, (x_start, x_end))| Span::new(y, x_start, x_end)).collect()
+}
+
+/// Calculates the spans of the border of `rect` that is `width` pixels wide,
+/// with its corners rounded off by `radius`, from the bottom up.
+///
+/// The border covers the filled rectangle up to, but not including, the
+/// rectangle `width` pixels inside of it, whose corners are rounded off by
+/// `width` less. With square corners that is the outline of the rectangle
+/// `(width - 1) / 2` pixels inside of `rect` stroked with a
+/// [`Stroke`](crate::thick_line::Stroke) of the same width. A border as wide
+/// as half of the rectangle fills it.
+pub fn calc_rect_border_spans(rect: Rect, radius: i32, width: u32) -> Vec<Span> {
+    if width == 0 {
+        return vec![];
+    }
+    let outer = calc_rect_spans(rect, radius);
+    let inset = i32::try_from(width).unwrap_or(i32::MAX);
+    if rect.width() < inset.saturating_mul(2) || rect.height() < inset.saturating_mul(2) {
+        return outer;
+    }
+
+    let inner_rect = Rect::new(rect.min + Point::new(inset, inset), rect.max - Point::new(inset, inset));
+    let inner: BTreeMap<i32, Span> =
+        calc_rect_spans(inner_rect, radius.max(0) - inset).into_iter().map(|span| (span.y, span)).collect();
+
+    let mut spans = vec![];
+    for span in outer {
+        match inner.get(&span.y) {
+            Some(hole) => {
+                spans.push(Span::new(span.y, span.x_start, hole.x_start - 1));
+                spans.push(Span::new(span.y, hole.x_end + 1, span.x_end));
+            }
+            None => spans.push(span),
+        }
+    }
+    spans
+}
+
+/// Draws `rect` with its corners rounded off by `radius` onto `canvas`
+/// through its viewport, as its outline, as a border or filled as `style`
+/// says.
+pub fn draw_rect<C: Canvas>(rect: Rect, radius: i32, style: Style, color: Rgba<u8>, canvas: &mut C) {
+    match style {
+        Style::Outline => {
+            for point in calc_rect_points(rect, radius) {
+                canvas.put_world_pixel(point, color);
+            }
+        }
+        Style::Border(width) => fill_spans(&calc_rect_border_spans(rect, radius, width), color, canvas),
+        Style::Filled => fill_spans(&calc_rect_spans(rect, radius), color, canvas),
+    }
+}
@@ -0,0 +1,110 @@
+//! Seed fill algorithms, which fill the region around a seed pixel.
+//!
+//! Boundary fill spreads from the seed until it reaches pixels of the
+//! boundary color. Flood fill replaces every pixel connected to the seed that
+//! has the same color as the seed. Both keep the pixels still to visit on an
+//! explicit stack instead of recursing, so regions as large as the whole
+//! canvas do not overflow the call stack.
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::Point;
+
+/// Which neighbours of a pixel the fill spreads into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// The pixels left, right, above and below.
+    Four,
+    /// The four pixels of [`Connectivity::Four`] and the four diagonal ones.
+    /// The fill leaks through boundaries that are only connected diagonally,
+    /// like the ones the Mid-Point line and circle draw.
+    Eight,
+}
+
+impl Connectivity {
+    fn neighbours(&self) -> &'static [(i64, i64)] {
+        match self {
+            Connectivity::Four => &[(1, 0), (0, 1), (-1, 0), (0, -1)],
+            Connectivity::Eight => &[(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)],
+        }
+    }
+}
+
+/// Fills the region around `seed` with `color`, up to the pixels of the
+/// `boundary` color. `seed` is mapped through the viewport of the canvas.
+///
+/// Pixels that already have the fill color stop the fill as well. Returns the
+/// number of pixels filled, which is 0 when the seed is outside of the canvas
+/// or on the boundary.
+pub fn boundary_fill<C: Canvas>(
+    seed: Point,
+    boundary: Rgba<u8>,
+    color: Rgba<u8>,
+    connectivity: Connectivity,
+    canvas: &mut C,
+) -> usize {
+    let seed = device_seed(seed, canvas);
+    seed_fill(seed, color, connectivity, canvas, |pixel| pixel != boundary && pixel != color)
+}
+
+/// Replaces the color of `seed` with `color` in the region connected to
+/// `seed`, which is mapped through the viewport of the canvas.
+///
+/// Returns the number of pixels filled, which is 0 when the seed is outside of
+/// the canvas or already has `color`.
+pub fn flood_fill<C: Canvas>(seed: Point, color: Rgba<u8>, connectivity: Connectivity, canvas: &mut C) -> usize {
+    let (x, y) = device_seed(seed, canvas);
+    if !is_on_canvas(x, y, canvas) {
+        return 0;
+    }
+    let target = canvas.get_pixel(x as u32, y as u32);
+    if target == color {
+        return 0;
+    }
+
+    seed_fill((x, y), color, connectivity, canvas, |pixel| pixel == target)
+}
+
+/// The canvas pixel the world point `seed` lands on.
+fn device_seed<C: Canvas>(seed: Point, canvas: &C) -> (i64, i64) {
+    let seed = canvas.viewport().to_device(seed);
+    (i64::from(seed.x), i64::from(seed.y))
+}
+
+/// Fills the canvas pixels connected to `seed` for which `is_inside` holds.
+///
+/// `is_inside` must not hold for `color`, which is how filled pixels are
+/// told apart from the ones still to fill.
+fn seed_fill<C: Canvas, F: Fn(Rgba<u8>) -> bool>(
+    seed: (i64, i64),
+    color: Rgba<u8>,
+    connectivity: Connectivity,
+    canvas: &mut C,
+    is_inside: F,
+) -> usize {
+    let mut filled = 0;
+    let mut stack = vec![];
+
+    let mut visit = |x: i64, y: i64, stack: &mut Vec<(i64, i64)>, canvas: &mut C| {
+        if is_on_canvas(x, y, canvas) && is_inside(canvas.get_pixel(x as u32, y as u32)) {
+            // Filled right away, so no pixel is pushed twice.
+            canvas.put_pixel(x as u32, y as u32, color);
+            stack.push((x, y));
+            filled += 1;
+        }
+    };
+
+    visit(seed.0, seed.1, &mut stack, canvas);
+    while let Some((x, y)) = stack.pop() {
+        for (dx, dy) in connectivity.neighbours() {
+            visit(x + dx, y + dy, &mut stack, canvas);
+        }
+    }
+
+    filled
+}
+
+fn is_on_canvas<C: Canvas>(x: i64, y: i64, canvas: &C) -> bool {
+    x >= 0 && y >= 0 && x < i64::from(canvas.width()) && y < i64::from(canvas.height())
+}
@@ -0,0 +1,75 @@
+//! Polygon clipping with the Sutherland–Hodgman algorithm.
+//!
+//! The polygon is clipped against one edge of the clip window at a time.
+//! Walking around the polygon, vertices inside of the edge are kept, vertices
+//! outside are dropped, and where an edge of the polygon crosses the clip
+//! edge the crossing point is added. After the last clip edge only the part
+//! inside of the window is left. The window has to be convex.
+
+use crate::geometry::{Point, Polygon};
+
+/// Clips `subject` to the convex polygon `window`, boundary included.
+///
+/// The window may be walked either way round. The result keeps the direction
+/// of `subject` and has no vertices if no part of it lies in the window, or if
+/// the window has no area. The crossings are only rounded to the closest
+/// pixels at the end, so the rounding does not add up over the clip edges.
+/// The result can be passed straight to
+/// [`fill_polygon`](crate::polygon_fill::fill_polygon).
+///
+/// Where a concave subject leaves the window and comes back, the parts inside
+/// stay joined by edges along the border of the window. Use
+/// [`weiler_atherton::clip_polygon`](crate::weiler_atherton::clip_polygon) to
+/// get them as separate polygons.
+pub fn clip_polygon(subject: &Polygon, window: &Polygon) -> Polygon {
+    let area = window.signed_area();
+    if window.vertices.len() < 3 || area == 0.0 {
+        return Polygon::new(vec![]);
+    }
+    let window = if area > 0.0 { window.clone() } else { window.reversed() };
+
+    let mut vertices: Vec<(f64, f64)> = subject
+        .vertices
+        .iter()
+        .map(|vertex| (f64::from(vertex.x), f64::from(vertex.y)))
+        .collect();
+
+    for edge in window.edges() {
+        let a = (f64::from(edge.start.x), f64::from(edge.start.y));
+        let b = (f64::from(edge.end.x), f64::from(edge.end.y));
+        // Positive on the inside of the edge, which is on its left.
+        let side = |(x, y): (f64, f64)| (b.0 - a.0) * (y - a.1) - (b.1 - a.1) * (x - a.0);
+
+        let input = std::mem::take(&mut vertices);
+        for (i, &current) in input.iter().enumerate() {
+            let previous = input[(i + input.len() - 1) % input.len()];
+            let (previous_side, current_side) = (side(previous), side(current));
+
+            if (previous_side >= 0.0) != (current_side >= 0.0) {
+                let t = previous_side / (previous_side - current_side);
+                vertices.push((previous.0 + t * (current.0 - previous.0), previous.1 + t * (current.1 - previous.1)));
+            }
+            if current_side >= 0.0 {
+                vertices.push(current);
+            }
+        }
+    }
+
+    Polygon::new(round_vertices(&vertices))
+}
+
+/// Rounds `vertices` to the closest pixels and drops the ones that end up on
+/// the same pixel as the vertex before them.
+pub(crate) fn round_vertices(vertices: &[(f64, f64)]) -> Vec<Point> {
+    let mut rounded: Vec<Point> = vec![];
+    for &(x, y) in vertices {
+        let point = Point::new(x.round() as i32, y.round() as i32);
+        if rounded.last() != Some(&point) {
+            rounded.push(point);
+        }
+    }
+    while rounded.len() > 1 && rounded.first() == rounded.last() {
+        rounded.pop();
+    }
+    rounded
+}
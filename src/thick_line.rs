@@ -0,0 +1,320 @@
+//! Lines wider than one pixel, with caps at their ends and joins between the
+//! segments of a polyline.
+//!
+//! Every segment is walked along the Mid-Point line through its middle, the
+//! spine. Each spine pixel is widened along the minor axis of the line, and
+//! of those pixels the ones whose center lies within half of the width of
+//! the exact segment are kept.
+//!
+//! A pixel center exactly half of the width away from a segment only counts
+//! on the left side of it, looking from its start to its end. That way a
+//! horizontal or vertical line of width `n` is exactly `n` pixels wide, and
+//! the outline of a counter-clockwise polygon grows into the polygon.
+
+use std::collections::HashSet;
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Line, Point, PointF, Polygon, Span};
+use crate::midpoint_line::calc_line_points;
+use crate::polygon_fill::fill_spans;
+
+/// Miters longer than this many times half of the width become bevels, like
+/// the default miter limit of SVG.
+pub const MITER_LIMIT: f64 = 4.0;
+
+/// Slack for the comparisons of pixel centers with the edges of a stroke.
+const EPSILON: f64 = 1e-9;
+
+/// The shape of the open ends of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cap {
+    /// The line ends square at its end points.
+    Butt,
+    /// The line ends square, half of the width past its end points.
+    Square,
+    /// The line ends in a half disc around its end points.
+    Round,
+}
+
+/// The shape of the outer corner where two segments of a polyline meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Join {
+    /// The outer edges are extended until they meet, up to [`MITER_LIMIT`].
+    Miter,
+    /// The outer corner is cut off straight.
+    Bevel,
+    /// The outer corner is rounded off around the vertex.
+    Round,
+}
+
+/// How lines are stroked: their width in pixels, their caps and their joins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stroke {
+    pub width: u32,
+    pub cap: Cap,
+    pub join: Join,
+}
+
+impl Stroke {
+    /// A stroke of `width` pixels with butt caps and miter joins.
+    pub fn new(width: u32) -> Self {
+        Self {
+            width,
+            cap: Cap::Butt,
+            join: Join::Miter,
+        }
+    }
+
+    pub fn with_cap(mut self, cap: Cap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    pub fn with_join(mut self, join: Join) -> Self {
+        self.join = join;
+        self
+    }
+
+    fn half_width(&self) -> f64 {
+        f64::from(self.width) / 2.0
+    }
+}
+
+/// Calculates the spans of `line` stroked with `stroke`, row by row from the
+/// bottom up and left to right within a row.
+///
+/// A line whose ends are the same point is a dot for round and square caps
+/// and has no spans with butt caps.
+pub fn calc_thick_line_spans(line: Line, stroke: &Stroke) -> Vec<Span> {
+    stroke_path(&[line.start, line.end], false, stroke)
+}
+
+/// Calculates the spans of the open polyline through `points` stroked with
+/// `stroke`, with its joins between the segments and its caps at both ends.
+pub fn calc_polyline_spans(points: &[Point], stroke: &Stroke) -> Vec<Span> {
+    stroke_path(points, false, stroke)
+}
+
+/// Calculates the spans of the outline of `polygon` stroked with `stroke`,
+/// which has joins at every vertex and no caps.
+pub fn calc_outline_spans(polygon: &Polygon, stroke: &Stroke) -> Vec<Span> {
+    stroke_path(&polygon.vertices, true, stroke)
+}
+
+/// Draws `line` onto `canvas` through its viewport, stroked with `stroke`.
+pub fn draw_thick_line<C: Canvas>(line: Line, stroke: &Stroke, color: Rgba<u8>, canvas: &mut C) {
+    fill_spans(&calc_thick_line_spans(line, stroke), color, canvas);
+}
+
+/// Draws the open polyline through `points` onto `canvas` through its
+/// viewport, stroked with `stroke`.
+pub fn draw_polyline<C: Canvas>(points: &[Point], stroke: &Stroke, color: Rgba<u8>, canvas: &mut C) {
+    fill_spans(&calc_polyline_spans(points, stroke), color, canvas);
+}
+
+/// Draws the outline of `polygon` onto `canvas` through its viewport,
+/// stroked with `stroke`.
+pub fn draw_outline<C: Canvas>(polygon: &Polygon, stroke: &Stroke, color: Rgba<u8>, canvas: &mut C) {
+    fill_spans(&calc_outline_spans(polygon, stroke), color, canvas);
+}
+
+/// A segment of a path, measured from its start.
+struct Segment {
+    start: PointF,
+    end: PointF,
+    /// The unit vector from the start to the end.
+    direction: PointF,
+    length: f64,
+}
+
+impl Segment {
+    fn new(start: Point, end: Point) -> Self {
+        let (start, end) = (PointF::from(start), PointF::from(end));
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let length = dx.hypot(dy);
+        Self {
+            start,
+            end,
+            direction: PointF::new(dx / length, dy / length),
+            length,
+        }
+    }
+
+    /// How far `point` lies along the segment from its start.
+    fn along(&self, point: PointF) -> f64 {
+        (point.x - self.start.x) * self.direction.x + (point.y - self.start.y) * self.direction.y
+    }
+
+    /// How far `point` lies to the left of the segment.
+    fn across(&self, point: PointF) -> f64 {
+        (point.y - self.start.y) * self.direction.x - (point.x - self.start.x) * self.direction.y
+    }
+
+    /// Whether `point` lies within half of the width of the infinite line
+    /// through the segment, counting the left edge but not the right one.
+    fn in_band(&self, point: PointF, half_width: f64) -> bool {
+        let across = self.across(point);
+        across > -half_width + EPSILON && across <= half_width + EPSILON
+    }
+
+    /// Adds the pixels of the segment to `pixels`, from `from` to `to` along
+    /// the segment.
+    fn add_pixels(&self, from: f64, to: f64, half_width: f64, pixels: &mut HashSet<Point>) {
+        // The spine runs past both ends, so it also reaches the square caps.
+        let reach = half_width * 2.0 + 2.0;
+        let at = |t: f64| PointF::new(self.start.x + self.direction.x * t, self.start.y + self.direction.y * t);
+        let spine = Line::new(at(-reach).round(), at(self.length + reach).round());
+
+        let x_major = self.direction.x.abs() >= self.direction.y.abs();
+        let cos = self.direction.x.abs().max(self.direction.y.abs());
+        let radius = (half_width / cos).ceil() as i32 + 1;
+
+        for point in calc_line_points(spine) {
+            for k in -radius..=radius {
+                let pixel = if x_major { Point::new(point.x, point.y + k) } else { Point::new(point.x + k, point.y) };
+                let center = PointF::from(pixel);
+                let t = self.along(center);
+                if t >= from - EPSILON && t <= to + EPSILON && self.in_band(center, half_width) {
+                    pixels.insert(pixel);
+                }
+            }
+        }
+    }
+}
+
+/// Strokes the path through `points`, which goes back to the first point if
+/// it is `closed`.
+fn stroke_path(points: &[Point], closed: bool, stroke: &Stroke) -> Vec<Span> {
+    let half_width = stroke.half_width();
+    let mut points = points.to_vec();
+    points.dedup();
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    let mut pixels = HashSet::new();
+    if stroke.width == 0 || points.is_empty() {
+        return vec![];
+    } else if points.len() == 1 {
+        add_dot(points[0], stroke, &mut pixels);
+        return into_spans(pixels);
+    }
+
+    let segment_count = if closed { points.len() } else { points.len() - 1 };
+    let segments: Vec<Segment> =
+        (0..segment_count).map(|i| Segment::new(points[i], points[(i + 1) % points.len()])).collect();
+
+    for (i, segment) in segments.iter().enumerate() {
+        let square = |end: bool| !closed && stroke.cap == Cap::Square && end;
+        let from = if square(i == 0) { -half_width } else { 0.0 };
+        let to = segment.length + if square(i + 1 == segment_count) { half_width } else { 0.0 };
+        segment.add_pixels(from, to, half_width, &mut pixels);
+    }
+
+    let joins = if closed { segment_count } else { segment_count - 1 };
+    for i in 0..joins {
+        add_join(&segments[i], &segments[(i + 1) % segment_count], stroke, &mut pixels);
+    }
+
+    if !closed && stroke.cap == Cap::Round {
+        add_disc(segments[0].start, half_width, &mut pixels);
+        add_disc(segments[segment_count - 1].end, half_width, &mut pixels);
+    }
+
+    into_spans(pixels)
+}
+
+/// Adds the outer corner between `first`, which ends at the vertex, and
+/// `second`, which starts there.
+fn add_join(first: &Segment, second: &Segment, stroke: &Stroke, pixels: &mut HashSet<Point>) {
+    let half_width = stroke.half_width();
+    let vertex = first.end;
+    if stroke.join == Join::Round {
+        add_disc(vertex, half_width, pixels);
+        return;
+    }
+
+    let (u, v) = (first.direction, second.direction);
+    let cross = u.x * v.y - u.y * v.x;
+    if cross.abs() < EPSILON {
+        // Straight on, or straight back, where there is no corner to fill.
+        return;
+    }
+
+    // The sine of half of the angle between the segments, and the bisector of
+    // the outer corner.
+    let sine = (u.x + v.x).hypot(u.y + v.y) / 2.0;
+    let length = (u.x - v.x).hypot(u.y - v.y);
+    let outer = PointF::new((u.x - v.x) / length, (u.y - v.y) / length);
+    let miter = stroke.join == Join::Miter && 1.0 / sine <= MITER_LIMIT;
+
+    // The corner is where both bands overlap past the ends of both segments,
+    // at most a miter away from the vertex.
+    let reach = (half_width * MITER_LIMIT).ceil() as i32 + 1;
+    let center = vertex.round();
+    for y in center.y - reach..=center.y + reach {
+        for x in center.x - reach..=center.x + reach {
+            let pixel = PointF::new(f64::from(x), f64::from(y));
+            let in_corner = first.along(pixel) >= first.length - EPSILON
+                && second.along(pixel) <= EPSILON
+                && first.in_band(pixel, half_width)
+                && second.in_band(pixel, half_width);
+            let towards = (pixel.x - vertex.x) * outer.x + (pixel.y - vertex.y) * outer.y;
+            if in_corner && (miter || towards <= half_width * sine + EPSILON) {
+                pixels.insert(Point::new(x, y));
+            }
+        }
+    }
+}
+
+/// Adds the pixels whose centers lie within `radius` of `center`.
+fn add_disc(center: PointF, radius: f64, pixels: &mut HashSet<Point>) {
+    let reach = radius.ceil() as i32 + 1;
+    let middle = center.round();
+    for y in middle.y - reach..=middle.y + reach {
+        for x in middle.x - reach..=middle.x + reach {
+            if (f64::from(x) - center.x).hypot(f64::from(y) - center.y) <= radius + EPSILON {
+                pixels.insert(Point::new(x, y));
+            }
+        }
+    }
+}
+
+/// Adds a path of a single point, which round and square caps turn into a
+/// dot and butt caps leave out.
+fn add_dot(point: Point, stroke: &Stroke, pixels: &mut HashSet<Point>) {
+    let half_width = stroke.half_width();
+    match stroke.cap {
+        Cap::Butt => {}
+        Cap::Round => add_disc(PointF::from(point), half_width, pixels),
+        Cap::Square => {
+            // Like a horizontal line of length 0, `width` pixels square.
+            let reach = half_width.ceil() as i32;
+            for dy in -reach..=reach {
+                for dx in -reach..=reach {
+                    let inside = |d: i32| f64::from(d) > -half_width + EPSILON && f64::from(d) <= half_width + EPSILON;
+                    if inside(dx) && inside(dy) {
+                        pixels.insert(Point::new(point.x + dx, point.y + dy));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Merges `pixels` into spans, row by row from the bottom up.
+fn into_spans(pixels: HashSet<Point>) -> Vec<Span> {
+    let mut pixels: Vec<Point> = pixels.into_iter().collect();
+    pixels.sort_by_key(|point| (point.y, point.x));
+
+    let mut spans: Vec<Span> = vec![];
+    for point in pixels {
+        match spans.last_mut() {
+            Some(span) if span.y == point.y && span.x_end + 1 == point.x => span.x_end = point.x,
+            _ => spans.push(Span::new(point.y, point.x, point.x)),
+        }
+    }
+    spans
+}
@@ -0,0 +1,152 @@
+//! 2D transformations as 3×3 matrices in homogeneous coordinates.
+//!
+//! A point (x, y) is the column vector (x, y, 1), so translations are
+//! matrices like every other transformation and a chain of transformations
+//! is the product of their matrices.
+
+use std::ops::Mul;
+
+use crate::geometry::{Line, Point, PointF, Polygon};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    matrix: [[f64; 3]; 3],
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Transform2D::identity()
+    }
+}
+
+impl Transform2D {
+    pub fn new(matrix: [[f64; 3]; 3]) -> Self {
+        Self { matrix }
+    }
+
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix
+    }
+
+    /// The transformation that leaves every point where it is.
+    pub fn identity() -> Self {
+        Self::linear(1.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Self::new([[1.0, 0.0, dx], [0.0, 1.0, dy], [0.0, 0.0, 1.0]])
+    }
+
+    /// Rotates counter-clockwise by `degrees` around the origin.
+    pub fn rotate(degrees: f64) -> Self {
+        let (cos, sin) = cos_sin(degrees);
+        Self::linear(cos, -sin, sin, cos)
+    }
+
+    /// Scales by `sx` along X and `sy` along Y, away from the origin.
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self::linear(sx, 0.0, 0.0, sy)
+    }
+
+    /// Shears X by `shx` times Y and Y by `shy` times X.
+    pub fn shear(shx: f64, shy: f64) -> Self {
+        Self::linear(1.0, shx, shy, 1.0)
+    }
+
+    /// Mirrors across the line through the origin at `degrees` from the X
+    /// axis: 0° mirrors across the X axis and 45° swaps X and Y.
+    pub fn reflect(degrees: f64) -> Self {
+        let (cos, sin) = cos_sin(2.0 * degrees);
+        Self::linear(cos, sin, sin, -cos)
+    }
+
+    fn linear(a: f64, b: f64, c: f64, d: f64) -> Self {
+        Self::new([[a, b, 0.0], [c, d, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// This transformation followed by `next`.
+    pub fn then(&self, next: &Transform2D) -> Transform2D {
+        *next * *self
+    }
+
+    /// The transformation that undoes this one, or `None` if this one
+    /// collapses the plane, like a scale by 0.
+    pub fn inverse(&self) -> Option<Transform2D> {
+        let m = &self.matrix;
+        let minor = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+
+        let determinant = m[0][0] * minor(1, 2, 1, 2) - m[0][1] * minor(1, 2, 0, 2) + m[0][2] * minor(1, 2, 0, 1);
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        // The adjugate, the transposed matrix of cofactors.
+        let adjugate = [
+            [minor(1, 2, 1, 2), -minor(0, 2, 1, 2), minor(0, 1, 1, 2)],
+            [-minor(1, 2, 0, 2), minor(0, 2, 0, 2), -minor(0, 1, 0, 2)],
+            [minor(1, 2, 0, 1), -minor(0, 2, 0, 1), minor(0, 1, 0, 1)],
+        ];
+        let mut inverse = [[0.0; 3]; 3];
+        for (row, adjugate_row) in inverse.iter_mut().zip(adjugate.iter()) {
+            for (value, cofactor) in row.iter_mut().zip(adjugate_row.iter()) {
+                *value = cofactor / determinant;
+            }
+        }
+        Some(Transform2D::new(inverse))
+    }
+
+    pub fn apply_f(&self, point: PointF) -> PointF {
+        let m = &self.matrix;
+        let x = m[0][0] * point.x + m[0][1] * point.y + m[0][2];
+        let y = m[1][0] * point.x + m[1][1] * point.y + m[1][2];
+        let w = m[2][0] * point.x + m[2][1] * point.y + m[2][2];
+        PointF::new(x / w, y / w)
+    }
+
+    /// Transforms `point` and rounds it to the closest pixel. Coordinates
+    /// beyond the `i32` limits are cut off at the limits.
+    pub fn apply(&self, point: Point) -> Point {
+        self.apply_f(PointF::from(point)).round()
+    }
+
+    pub fn apply_line(&self, line: Line) -> Line {
+        Line::new(self.apply(line.start), self.apply(line.end))
+    }
+
+    pub fn apply_polygon(&self, polygon: &Polygon) -> Polygon {
+        Polygon::new(polygon.vertices.iter().map(|vertex| self.apply(*vertex)).collect())
+    }
+}
+
+impl Mul for Transform2D {
+    type Output = Transform2D;
+
+    /// The product of the matrices, which applies `other` first and `self`
+    /// second.
+    fn mul(self, other: Transform2D) -> Transform2D {
+        let mut product = [[0.0; 3]; 3];
+        for (r, row) in product.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.matrix[r][k] * other.matrix[k][c]).sum();
+            }
+        }
+        Transform2D::new(product)
+    }
+}
+
+/// The cosine and sine of `degrees`, exact for multiples of 90°, so turns
+/// and mirrors by those angles keep integer points on integers.
+fn cos_sin(degrees: f64) -> (f64, f64) {
+    let turns = degrees / 90.0;
+    if turns.fract() == 0.0 {
+        match turns.rem_euclid(4.0) as u8 {
+            0 => (1.0, 0.0),
+            1 => (0.0, 1.0),
+            2 => (-1.0, 0.0),
+            _ => (0.0, -1.0),
+        }
+    } else {
+        let radians = degrees.to_radians();
+        (radians.cos(), radians.sin())
+    }
+}
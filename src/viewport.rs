@@ -0,0 +1,182 @@
+//! The mapping from world coordinates to the pixels of a canvas.
+//!
+//! The rasterizers work in world coordinates and every pixel they draw goes
+//! through the [`Viewport`] of the canvas, see [`Canvas::viewport`]. By
+//! default the world origin is at the center of the canvas and the Y axis
+//! points up.
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Point, PointF, Rect};
+use crate::transform::Transform2D;
+
+/// The direction the world Y axis points to on the canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    Up,
+    Down,
+}
+
+/// Maps world coordinates to device coordinates, the pixels of a canvas
+/// with (0, 0) at the top left.
+///
+/// A world pixel covers the square of side 1 around its coordinates. When
+/// zoomed in, it covers a block of device pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// Where the world origin lands, in device coordinates.
+    origin: PointF,
+    /// Device pixels per world unit along X and Y.
+    scale: (f64, f64),
+    y_axis: YAxis,
+}
+
+impl Viewport {
+    /// The world origin at the center of a canvas of `width` by `height`
+    /// pixels, the Y axis pointing up and one device pixel per world pixel.
+    pub fn centered(width: u32, height: u32) -> Self {
+        Self {
+            origin: PointF::new(f64::from(width / 2), f64::from(height / 2)),
+            scale: (1.0, 1.0),
+            y_axis: YAxis::Up,
+        }
+    }
+
+    /// World coordinates that are device coordinates: the origin at the top
+    /// left and the Y axis pointing down.
+    pub fn screen() -> Self {
+        Self {
+            origin: PointF::new(0.0, 0.0),
+            scale: (1.0, 1.0),
+            y_axis: YAxis::Down,
+        }
+    }
+
+    /// The window-to-viewport transformation, which stretches the world
+    /// pixels of `window` over the device pixels of `device`. With
+    /// [`YAxis::Up`] the bottom of the window lands on the bottom of the
+    /// device rectangle.
+    pub fn from_window(window: Rect, device: Rect, y_axis: YAxis) -> Self {
+        let sx = (f64::from(device.width()) + 1.0) / (f64::from(window.width()) + 1.0);
+        let sy = (f64::from(device.height()) + 1.0) / (f64::from(window.height()) + 1.0);
+
+        // The outer edges of the pixels at `min` line up.
+        let origin_x = f64::from(device.min.x) - 0.5 - sx * (f64::from(window.min.x) - 0.5);
+        let origin_y = match y_axis {
+            YAxis::Up => f64::from(device.max.y) + 0.5 + sy * (f64::from(window.min.y) - 0.5),
+            YAxis::Down => f64::from(device.min.y) - 0.5 - sy * (f64::from(window.min.y) - 0.5),
+        };
+
+        Self {
+            origin: PointF::new(origin_x, origin_y),
+            scale: (sx, sy),
+            y_axis,
+        }
+    }
+
+    /// Moves the world origin to `origin`, in device coordinates.
+    pub fn with_origin(mut self, origin: PointF) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Multiplies the size of the world pixels on the device by `zoom`,
+    /// around the world origin.
+    pub fn with_zoom(mut self, zoom: f64) -> Self {
+        self.scale = (self.scale.0 * zoom, self.scale.1 * zoom);
+        self
+    }
+
+    pub fn origin(&self) -> PointF {
+        self.origin
+    }
+
+    pub fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    /// The transformation from world to device coordinates.
+    pub fn transform(&self) -> Transform2D {
+        let sy = match self.y_axis {
+            YAxis::Up => -self.scale.1,
+            YAxis::Down => self.scale.1,
+        };
+        Transform2D::scale(self.scale.0, sy).then(&Transform2D::translate(self.origin.x, self.origin.y))
+    }
+
+    /// The device pixel the center of the world pixel `point` lands on.
+    pub fn to_device(&self, point: Point) -> Point {
+        self.transform().apply(point)
+    }
+
+    /// The world pixel that covers the device pixel `point`, or `None` for a
+    /// viewport that shrinks the world to nothing.
+    pub fn to_world(&self, point: Point) -> Option<Point> {
+        Some(self.transform().inverse()?.apply(point))
+    }
+
+    /// The device pixels the world pixel `point` covers, as the ranges
+    /// `x_start..x_end` and `y_start..y_end`. When zoomed out so far that it
+    /// covers no pixel, it is given the pixel its center lands on.
+    pub fn device_pixels(&self, point: Point) -> ([i64; 2], [i64; 2]) {
+        let transform = self.transform();
+        let corner = |dx: f64, dy: f64| transform.apply_f(PointF::new(f64::from(point.x) + dx, f64::from(point.y) + dy));
+        let (a, b) = (corner(-0.5, -0.5), corner(0.5, 0.5));
+
+        let range = |a: f64, b: f64| {
+            let (low, high) = if a < b { (a, b) } else { (b, a) };
+            let (start, end) = ((low + 0.5).floor() as i64, (high + 0.5).floor() as i64);
+            if start < end {
+                [start, end]
+            } else {
+                let center = ((low + high) / 2.0 + 0.5).floor() as i64;
+                [center, center + 1]
+            }
+        };
+        (range(a.x, b.x), range(a.y, b.y))
+    }
+
+    /// Wraps `canvas` so everything drawn onto it goes through this
+    /// viewport.
+    pub fn canvas<C: Canvas>(self, canvas: &mut C) -> ViewportCanvas<'_, C> {
+        ViewportCanvas { canvas, viewport: self }
+    }
+}
+
+/// A canvas drawn onto through a viewport other than its default one.
+pub struct ViewportCanvas<'a, C: Canvas> {
+    canvas: &'a mut C,
+    viewport: Viewport,
+}
+
+impl<'a, C: Canvas> Canvas for ViewportCanvas<'a, C> {
+    fn width(&self) -> u32 {
+        self.canvas.width()
+    }
+
+    fn height(&self) -> u32 {
+        self.canvas.height()
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, color: Rgba<u8>) {
+        self.canvas.put_pixel(x, y, color);
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Rgba<u8> {
+        self.canvas.get_pixel(x, y)
+    }
+
+    fn clear(&mut self, color: Rgba<u8>) {
+        self.canvas.clear(color);
+    }
+
+    fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+}
@@ -0,0 +1,191 @@
+//! Polygon clipping with the Weiler–Atherton algorithm.
+//!
+//! The points where the edges of the polygon and of the clip window cross
+//! are inserted into both vertex lists and marked as entering or leaving the
+//! window. Starting from an entering crossing, the polygon is followed until
+//! it leaves the window, then the window is followed until the polygon enters
+//! it again, and so on until the start is reached. Every entering crossing
+//! that is not on such a loop starts a new output polygon, so both the
+//! polygon and the window may be concave.
+
+use crate::geometry::Polygon;
+use crate::sutherland_hodgman::round_vertices;
+
+/// How far the window is moved before clipping, in pixels. The two numbers
+/// have no simple ratio, so no vertex ends up exactly on an edge of the other
+/// polygon and no edges overlap.
+const NUDGE: (f64, f64) = (1.0e-6, 1.4142135e-6);
+
+type Vertex = (f64, f64);
+
+/// A point where an edge of the polygon crosses an edge of the window.
+#[derive(Debug, Clone, Copy)]
+struct Crossing {
+    point: Vertex,
+    /// The edge of the polygon and how far along it the crossing is.
+    subject: (usize, f64),
+    /// The edge of the window and how far along it the crossing is.
+    window: (usize, f64),
+    entering: bool,
+}
+
+/// A vertex list with the crossings inserted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Node {
+    Vertex(Vertex),
+    Crossing(usize),
+}
+
+/// Clips `subject` to `window`, where both may be concave, and returns the
+/// parts of `subject` inside of the window.
+///
+/// Both polygons must not cross themselves but may be walked either way
+/// round; the result goes counter-clockwise. Nothing is returned if no part
+/// of `subject` lies in the window or if either polygon has no area. Like
+/// [`sutherland_hodgman::clip_polygon`](crate::sutherland_hodgman::clip_polygon),
+/// the vertices are rounded to the closest pixels at the end.
+///
+/// The window is moved by a millionth of a pixel first, which keeps vertices
+/// on the edges of the other polygon and shared edges from needing special
+/// cases. The rounding takes the result back to the pixels.
+pub fn clip_polygon(subject: &Polygon, window: &Polygon) -> Vec<Polygon> {
+    let (subject, window) = match (counter_clockwise(subject), counter_clockwise(window)) {
+        (Some(subject), Some(window)) => (subject, window),
+        _ => return vec![],
+    };
+    let subject: Vec<Vertex> = subject.vertices.iter().map(|v| (f64::from(v.x), f64::from(v.y))).collect();
+    let nudged: Vec<Vertex> = window
+        .vertices
+        .iter()
+        .map(|v| (f64::from(v.x) + NUDGE.0, f64::from(v.y) + NUDGE.1))
+        .collect();
+
+    let crossings = find_crossings(&subject, &nudged);
+    if crossings.is_empty() {
+        // One polygon is inside of the other one, or they are apart.
+        return if contains(&nudged, subject[0]) {
+            vec![Polygon::new(round_vertices(&subject))]
+        } else if contains(&subject, nudged[0]) {
+            vec![window]
+        } else {
+            vec![]
+        };
+    }
+
+    let subject_nodes = insert_crossings(&subject, &crossings, |crossing| crossing.subject);
+    let window_nodes = insert_crossings(&nudged, &crossings, |crossing| crossing.window);
+    let position = |nodes: &[Node], index: usize| nodes.iter().position(|node| *node == Node::Crossing(index)).unwrap();
+
+    let mut visited = vec![false; crossings.len()];
+    let mut polygons = vec![];
+    for start in 0..crossings.len() {
+        if visited[start] || !crossings[start].entering {
+            continue;
+        }
+
+        let mut vertices = vec![];
+        let mut current = start;
+        // Inside of the window the polygon is followed, outside the window.
+        let mut on_subject = true;
+        loop {
+            visited[current] = true;
+            vertices.push(crossings[current].point);
+
+            let nodes = if on_subject { &subject_nodes } else { &window_nodes };
+            let mut i = position(nodes, current);
+            current = loop {
+                i = (i + 1) % nodes.len();
+                match nodes[i] {
+                    Node::Vertex(vertex) => vertices.push(vertex),
+                    Node::Crossing(index) => break index,
+                }
+            };
+            on_subject = !on_subject;
+
+            if current == start {
+                break;
+            }
+        }
+
+        let vertices = round_vertices(&vertices);
+        if vertices.len() >= 3 {
+            polygons.push(Polygon::new(vertices));
+        }
+    }
+
+    polygons
+}
+
+/// The polygon walked counter-clockwise, or `None` if it has no area.
+fn counter_clockwise(polygon: &Polygon) -> Option<Polygon> {
+    let area = polygon.signed_area();
+    if polygon.vertices.len() < 3 || area == 0.0 {
+        None
+    } else if area > 0.0 {
+        Some(polygon.clone())
+    } else {
+        Some(polygon.reversed())
+    }
+}
+
+fn find_crossings(subject: &[Vertex], window: &[Vertex]) -> Vec<Crossing> {
+    let edges = |vertices: &[Vertex]| -> Vec<(Vertex, Vertex)> {
+        (0..vertices.len()).map(|i| (vertices[i], vertices[(i + 1) % vertices.len()])).collect()
+    };
+
+    let mut crossings = vec![];
+    for (i, &(p, p_end)) in edges(subject).iter().enumerate() {
+        for (j, &(q, q_end)) in edges(window).iter().enumerate() {
+            let d = (p_end.0 - p.0, p_end.1 - p.1);
+            let e = (q_end.0 - q.0, q_end.1 - q.1);
+            let denominator = d.0 * e.1 - d.1 * e.0;
+            if denominator == 0.0 {
+                continue;
+            }
+
+            let w = (q.0 - p.0, q.1 - p.1);
+            let t = (w.0 * e.1 - w.1 * e.0) / denominator;
+            let u = (w.0 * d.1 - w.1 * d.0) / denominator;
+            if (0.0..1.0).contains(&t) && (0.0..1.0).contains(&u) {
+                crossings.push(Crossing {
+                    point: (p.0 + t * d.0, p.1 + t * d.1),
+                    subject: (i, t),
+                    window: (j, u),
+                    // The inside of the window is on the left of its edges.
+                    entering: denominator < 0.0,
+                });
+            }
+        }
+    }
+    crossings
+}
+
+/// The vertices with the crossings inserted into their edges, in order along
+/// each edge. `place` gives the edge of a crossing and how far along it is.
+fn insert_crossings<F: Fn(&Crossing) -> (usize, f64)>(vertices: &[Vertex], crossings: &[Crossing], place: F) -> Vec<Node> {
+    let mut nodes = vec![];
+    for (edge, &vertex) in vertices.iter().enumerate() {
+        nodes.push(Node::Vertex(vertex));
+
+        let mut on_edge: Vec<usize> = (0..crossings.len()).filter(|&i| place(&crossings[i]).0 == edge).collect();
+        on_edge.sort_by(|&a, &b| place(&crossings[a]).1.partial_cmp(&place(&crossings[b]).1).unwrap());
+        nodes.extend(on_edge.into_iter().map(Node::Crossing));
+    }
+    nodes
+}
+
+/// Whether `point` lies inside of the polygon `vertices`, by counting how
+/// often a ray to the right crosses its edges.
+fn contains(vertices: &[Vertex], point: Vertex) -> bool {
+    let mut inside = false;
+    for i in 0..vertices.len() {
+        let (a, b) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+        if (a.1 > point.1) != (b.1 > point.1) {
+            let x = a.0 + (point.1 - a.1) * (b.0 - a.0) / (b.1 - a.1);
+            if point.0 < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
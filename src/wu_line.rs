@@ -0,0 +1,87 @@
+//! Anti-aliased lines with Xiaolin Wu's algorithm.
+//!
+//! Instead of picking one pixel per column like the Mid-Point line, Wu's
+//! algorithm splits every column between the two pixels the line passes
+//! between, in proportion to how close the line is to their centers. The
+//! coverage of a pixel is then used as its opacity when the line is blended
+//! onto the canvas.
+
+use image::Rgba;
+
+use crate::canvas::Canvas;
+use crate::geometry::{Point, PointF};
+
+/// Calculates the pixels of the anti-aliased line from `start` to `end`, each
+/// with its coverage between 0 and 1.
+///
+/// The two pixels of a column share a coverage of 1 between them. The
+/// columns of the end points are weighted by how much of them the line spans,
+/// so integer end points get half of the coverage, and a line of length zero
+/// covers nothing. Pixels with no coverage are left out, and lines with a
+/// non-finite coordinate have no pixels.
+pub fn calc_wu_points<P: Into<PointF>>(start: P, end: P) -> Vec<(Point, f64)> {
+    let (mut a, mut b) = (start.into(), end.into());
+    if ![a.x, a.y, b.x, b.y].iter().all(|c| c.is_finite()) {
+        return vec![];
+    }
+
+    // Walked along X, with the coordinates swapped for steep lines.
+    let steep = (b.y - a.y).abs() > (b.x - a.x).abs();
+    if steep {
+        a = PointF::new(a.y, a.x);
+        b = PointF::new(b.y, b.x);
+    }
+    if a.x > b.x {
+        std::mem::swap(&mut a, &mut b);
+    }
+
+    let delta_x = b.x - a.x;
+    let gradient = if delta_x == 0.0 { 0.0 } else { (b.y - a.y) / delta_x };
+
+    let mut points = vec![];
+    let mut plot = |x: f64, y: f64, coverage: f64| {
+        if coverage > 0.0 {
+            let point = if steep { Point::new(y as i32, x as i32) } else { Point::new(x as i32, y as i32) };
+            points.push((point, coverage));
+        }
+    };
+    // Splits the column `x` between the pixels above and below `y`.
+    let mut plot_column = |x: f64, y: f64, weight: f64| {
+        let below = y.floor();
+        plot(x, below, (1.0 - (y - below)) * weight);
+        plot(x, below + 1.0, (y - below) * weight);
+    };
+
+    let first = a.x.round();
+    let last = b.x.round();
+    if first == last {
+        // Both end points fall into the same column.
+        plot_column(first, (a.y + b.y) / 2.0, delta_x);
+        return points;
+    }
+
+    // The part of the end columns the line spans, from the end point to the
+    // edge of the column.
+    let first_weight = first + 0.5 - a.x;
+    let last_weight = b.x - (last - 0.5);
+
+    plot_column(first, a.y + gradient * (first - a.x), first_weight);
+    let mut y = a.y + gradient * (first + 1.0 - a.x);
+    let mut x = first + 1.0;
+    while x < last {
+        plot_column(x, y, 1.0);
+        y += gradient;
+        x += 1.0;
+    }
+    plot_column(last, b.y + gradient * (last - b.x), last_weight);
+
+    points
+}
+
+/// Blends the anti-aliased line from `start` to `end` onto `canvas` through
+/// its viewport, with the coverage of each pixel as its opacity.
+pub fn draw_line_wu<C: Canvas, P: Into<PointF>>(start: P, end: P, color: Rgba<u8>, canvas: &mut C) {
+    for (point, coverage) in calc_wu_points(start, end) {
+        canvas.blend_world_pixel(point, color, coverage);
+    }
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use graphics_rs::canvas::{Canvas, FrameBuffer};
+use graphics_rs::cohen_sutherland::{clip_line, draw_clipped_line, OutCode};
+use graphics_rs::geometry::{Line, Point, Rect};
+use graphics_rs::midpoint_line::draw_line;
+
+use common::{assert_golden, GREEN, RED};
+
+fn window() -> Rect {
+    Rect::new(Point::new(-10, -5), Point::new(20, 15))
+}
+
+fn line(x1: i32, y1: i32, x2: i32, y2: i32) -> Line {
+    Line::new(Point::new(x1, y1), Point::new(x2, y2))
+}
+
+#[test]
+fn outcodes() {
+    let window = window();
+
+    assert_eq!(OutCode::of(Point::new(0, 0), &window), OutCode::INSIDE);
+    assert_eq!(OutCode::of(Point::new(-10, 15), &window), OutCode::INSIDE);
+    assert_eq!(OutCode::of(Point::new(-11, 0), &window), OutCode::LEFT);
+    assert_eq!(OutCode::of(Point::new(21, 16), &window), OutCode::RIGHT | OutCode::TOP);
+    assert_eq!(OutCode::of(Point::new(-20, -6), &window), OutCode::LEFT | OutCode::BOTTOM);
+    assert!((OutCode::RIGHT | OutCode::TOP).contains(OutCode::TOP));
+    assert!(!OutCode::RIGHT.contains(OutCode::TOP));
+}
+
+#[test]
+fn lines_inside_are_kept() {
+    let inside = line(-10, -5, 20, 15);
+
+    assert_eq!(clip_line(inside, &window()), Some(inside));
+    assert_eq!(clip_line(line(3, 3, 3, 3), &window()), Some(line(3, 3, 3, 3)));
+}
+
+#[test]
+fn lines_outside_are_rejected() {
+    // Beyond a single side.
+    assert_eq!(clip_line(line(-30, -20, 40, -6), &window()), None);
+    // Beyond different sides, passing the corner.
+    assert_eq!(clip_line(line(-20, 5, 0, 30), &window()), None);
+    assert_eq!(clip_line(line(100, 100, 100, 100), &window()), None);
+}
+
+#[test]
+fn crossing_lines_end_on_the_window() {
+    assert_eq!(clip_line(line(-40, 0, 40, 0), &window()), Some(line(-10, 0, 20, 0)));
+    assert_eq!(clip_line(line(0, 40, 0, -40), &window()), Some(line(0, 15, 0, -5)));
+    // Corner to corner, through the diagonal of a square window.
+    let square = Rect::new(Point::new(0, 0), Point::new(10, 10));
+    assert_eq!(clip_line(line(-5, -5, 15, 15), &square), Some(line(0, 0, 10, 10)));
+}
+
+#[test]
+fn clipped_lines_stay_close_to_the_original() {
+    let window = window();
+    for &(x1, y1, x2, y2) in &[(-50, -30, 60, 40), (25, -20, -30, 30), (-13, 40, 17, -50), (5, 5, 300, 7)] {
+        let original = line(x1, y1, x2, y2);
+        let clipped = clip_line(original, &window).unwrap();
+
+        for point in &[clipped.start, clipped.end] {
+            assert!(window.contains(*point), "{:?} is outside", point);
+            let (dx, dy) = (f64::from(x2 - x1), f64::from(y2 - y1));
+            let (px, py) = (f64::from(point.x - x1), f64::from(point.y - y1));
+            let distance = (px * dy - py * dx).abs() / dx.hypot(dy);
+            assert!(distance <= 0.5, "{:?} is {} away from {:?}", point, distance, original);
+        }
+        // The direction is kept.
+        let (delta, original_delta) = (clipped.delta(), original.delta());
+        assert!(i64::from(delta.x) * i64::from(original_delta.x) >= 0);
+        assert!(i64::from(delta.y) * i64::from(original_delta.y) >= 0);
+    }
+}
+
+#[test]
+fn canvas_bounds_are_the_default_window() {
+    let canvas = FrameBuffer::new(64, 32);
+
+    assert_eq!(canvas.world_bounds(), Rect::new(Point::new(-32, -15), Point::new(31, 16)));
+}
+
+#[test]
+fn huge_lines_are_clipped_before_drawing() {
+    let mut clipped = FrameBuffer::new(64, 64);
+    let mut unclipped = FrameBuffer::new(64, 64);
+    let diagonal = line(-1_000_000_000, -1_000_000_000, 1_000_000_000, 1_000_000_000);
+
+    draw_clipped_line(diagonal, None, GREEN, &mut clipped);
+    draw_line(line(-100, -100, 100, 100), GREEN, &mut unclipped);
+
+    assert_eq!(clipped, unclipped);
+}
+
+#[test]
+fn clipped_lines() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    let window = Rect::new(Point::new(-40, -30), Point::new(40, 30));
+
+    for i in 0..12 {
+        let angle = f64::from(i) * 30.0_f64.to_radians();
+        let start = Point::new((90.0 * angle.cos()) as i32, (90.0 * angle.sin()) as i32);
+        let end = Point::new((-70.0 * (angle + 0.6).cos()) as i32, (-70.0 * (angle + 0.6).sin()) as i32);
+        draw_line(Line::new(start, end), RED, &mut canvas);
+        draw_clipped_line(Line::new(start, end), Some(window), GREEN, &mut canvas);
+    }
+
+    assert_golden("clipped_lines", &canvas);
+}
@@ -0,0 +1,63 @@
+//! Helpers shared by the integration tests.
+
+// Each test binary only uses some of the helpers.
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use graphics_rs::canvas::{Canvas, FrameBuffer};
+use image::Rgba;
+
+pub const GREEN: Rgba<u8> = Rgba([0, 255, 0, 255]);
+pub const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// Compares `canvas` pixel for pixel with `tests/golden/<name>.png`.
+///
+/// Run the tests with `UPDATE_GOLDEN=1` to write the reference images
+/// instead. On a mismatch the rendered image is saved next to the build
+/// output under `target/golden-failures` so it can be inspected.
+pub fn assert_golden(name: &str, canvas: &FrameBuffer) {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let reference = root.join("tests").join("golden").join(format!("{}.png", name));
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        canvas.to_image().save(&reference).expect("Could Not Save Reference Image");
+        return;
+    }
+
+    let expected = image::open(&reference)
+        .unwrap_or_else(|e| panic!("Could Not Open {}: {}. Run with UPDATE_GOLDEN=1 to create it.", reference.display(), e))
+        .to_rgba8();
+
+    let mismatches = if expected.dimensions() != (canvas.width(), canvas.height()) {
+        None
+    } else {
+        Some(
+            expected
+                .enumerate_pixels()
+                .filter(|(x, y, pixel)| canvas.get_pixel(*x, *y) != **pixel)
+                .count(),
+        )
+    };
+
+    if mismatches != Some(0) {
+        let failures = root.join("target").join("golden-failures");
+        fs::create_dir_all(&failures).unwrap();
+        let actual = failures.join(format!("{}.png", name));
+        canvas.to_image().save(&actual).unwrap();
+
+        match mismatches {
+            None => panic!(
+                "{}: rendered {}x{} but the reference is {}x{}",
+                name,
+                canvas.width(),
+                canvas.height(),
+                expected.width(),
+                expected.height()
+            ),
+            Some(count) => panic!("{}: {} pixels differ, see {}", name, count, actual.display()),
+        }
+    }
+}
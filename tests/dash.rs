@@ -0,0 +1,94 @@
+mod common;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::dash::{calc_dashed_polyline_points, draw_dashed, DashPattern};
+use graphics_rs::dda_line::calculate_points;
+use graphics_rs::geometry::{Circle, Line, Point};
+use graphics_rs::midpoint_circle::{calc_circle_points, draw_8way_axis};
+use graphics_rs::midpoint_line::{calc_line_points, draw_center_axis};
+
+use common::{assert_golden, GREEN, RED};
+
+/// The first `count` pixels of `pattern`, drawn as `#` and left out as `.`.
+fn run(pattern: &DashPattern, count: usize) -> String {
+    let mut dasher = pattern.dasher();
+    (0..count).map(|_| if dasher.next_is_on() { '#' } else { '.' }).collect()
+}
+
+#[test]
+fn dash_arrays_alternate_dashes_and_gaps() {
+    assert_eq!(run(&DashPattern::new(&[3, 2]), 12), "###..###..##");
+    assert_eq!(run(&DashPattern::new(&[4, 1, 1, 1]), 14), "####.#.####.#.");
+    // An odd array is repeated, so its lengths alternate between on and off.
+    assert_eq!(run(&DashPattern::new(&[2]), 8), "##..##..");
+    assert_eq!(run(&DashPattern::new(&[3, 1, 2]), 12), "###.##...#..");
+    assert_eq!(run(&DashPattern::dotted(), 7), "#..#..#");
+}
+
+#[test]
+fn empty_patterns_are_solid() {
+    for pattern in &[DashPattern::solid(), DashPattern::new(&[]), DashPattern::new(&[0, 0])] {
+        assert_eq!(run(pattern, 10), "##########");
+    }
+}
+
+#[test]
+fn masks_are_read_from_the_lowest_bit() {
+    assert_eq!(DashPattern::from_mask(0x00FF), DashPattern::new(&[8, 8]));
+    assert_eq!(run(&DashPattern::from_mask(0b0011_0000_0000_0111), 18), "###.........##..##");
+    assert_eq!(run(&DashPattern::from_mask(0xFFFF), 20), "####################");
+    assert_eq!(run(&DashPattern::from_mask(0), 20), "....................");
+}
+
+#[test]
+fn the_phase_shifts_the_pattern() {
+    let pattern = DashPattern::new(&[3, 2]);
+    assert_eq!(run(&pattern.clone().with_phase(1), 10), "##..###..#");
+    assert_eq!(run(&pattern.clone().with_phase(3), 10), "..###..###");
+    assert_eq!(run(&pattern.with_phase(5), 10), run(&DashPattern::new(&[3, 2]), 10));
+}
+
+#[test]
+fn dashes_follow_the_pixels_of_every_rasterizer() {
+    let pattern = DashPattern::new(&[5, 3]);
+    let streams = [
+        calc_line_points(Line::new(Point::new(-20, 7), Point::new(30, -9))),
+        calculate_points(Point::new(-20, 7), Point::new(30, -9)),
+        calc_circle_points(Circle::new(Point::new(3, 4), 25)),
+    ];
+    for points in &streams {
+        let dashed = pattern.dasher().apply(points.iter().copied());
+        let expected: Vec<Point> = points.iter().enumerate().filter(|(i, _)| i % 8 < 5).map(|(_, &point)| point).collect();
+        assert_eq!(dashed, expected);
+    }
+}
+
+#[test]
+fn the_phase_carries_on_across_polyline_segments() {
+    let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 7), Point::new(-3, 12)];
+    let pattern = DashPattern::new(&[4, 3]);
+
+    // The whole polyline, with the shared corners walked once.
+    let mut walk = calc_line_points(Line::new(points[0], points[1]));
+    for pair in points[1..].windows(2) {
+        walk.extend(calc_line_points(Line::new(pair[0], pair[1])).into_iter().skip(1));
+    }
+    let expected = pattern.dasher().apply(walk);
+
+    assert_eq!(calc_dashed_polyline_points(&points, &mut pattern.dasher()), expected);
+}
+
+#[test]
+fn dashed_lines_and_axes() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    draw_center_axis(&DashPattern::center(), RED, &mut canvas);
+    draw_8way_axis(&DashPattern::dotted(), RED, &mut canvas);
+
+    let hidden = DashPattern::hidden();
+    let line = calc_line_points(Line::new(Point::new(-50, 40), Point::new(50, 20)));
+    draw_dashed(line, &mut hidden.dasher(), GREEN, &mut canvas);
+    let circle = calc_circle_points(Circle::new(Point::new(20, -25), 30));
+    draw_dashed(circle, &mut hidden.dasher(), GREEN, &mut canvas);
+
+    assert_golden("dashed_lines_and_axes", &canvas);
+}
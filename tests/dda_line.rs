@@ -0,0 +1,56 @@
+use graphics_rs::dda_line::calculate_points;
+use graphics_rs::geometry::{Line, Point, PointF};
+use graphics_rs::midpoint_line::calc_line_points;
+
+#[test]
+fn vertical_lines() {
+    let down = calculate_points(Point::new(3, 2), Point::new(3, -1));
+    assert_eq!(down, vec![Point::new(3, 2), Point::new(3, 1), Point::new(3, 0), Point::new(3, -1)]);
+
+    let up = calculate_points(Point::new(3, -1), Point::new(3, 2));
+    assert_eq!(up, vec![Point::new(3, -1), Point::new(3, 0), Point::new(3, 1), Point::new(3, 2)]);
+}
+
+#[test]
+fn horizontal_lines() {
+    let left = calculate_points(Point::new(1, 5), Point::new(-2, 5));
+    assert_eq!(left, vec![Point::new(1, 5), Point::new(0, 5), Point::new(-1, 5), Point::new(-2, 5)]);
+}
+
+#[test]
+fn single_point() {
+    assert_eq!(calculate_points(Point::new(4, 4), Point::new(4, 4)), vec![Point::new(4, 4)]);
+    assert_eq!(calculate_points(PointF::new(4.2, 3.9), PointF::new(3.8, 4.1)), vec![Point::new(4, 4)]);
+}
+
+#[test]
+fn non_finite_points_have_no_pixels() {
+    assert!(calculate_points(PointF::new(f64::NAN, 0.0), PointF::new(1.0, 1.0)).is_empty());
+    assert!(calculate_points(PointF::new(0.0, 0.0), PointF::new(f64::INFINITY, 1.0)).is_empty());
+}
+
+#[test]
+fn sub_pixel_end_points() {
+    // The ideal line is y = x / 2 + 0.3, sampled at the centers of the
+    // columns 0 to 4.
+    let points = calculate_points(PointF::new(0.4, 0.5), PointF::new(4.4, 2.5));
+    let expected: Vec<Point> = [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)].iter().map(|&p| Point::from(p)).collect();
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn same_pixels_as_midpoint_line() {
+    let start = Point::new(-5, 11);
+    for dx in -30..=30 {
+        for dy in -30..=30 {
+            let end = start + Point::new(dx, dy);
+            assert_eq!(
+                calculate_points(start, end),
+                calc_line_points(Line::new(start, end)),
+                "from {:?} to {:?}",
+                start,
+                end
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Circle, Ellipse, Point, Span};
+use graphics_rs::midpoint_circle::{calc_circle_points, calc_circle_spans, calc_circle_spans_between, fill_circle};
+use graphics_rs::midpoint_ellipse::{calc_ellipse_points, calc_ellipse_spans, fill_ellipse};
+
+use common::{assert_golden, GREEN, RED};
+
+/// Checks that `spans` are one per row, from the bottom up, and reach out
+/// exactly to the leftmost and rightmost point of `outline` on every row.
+fn assert_spans_fill(spans: &[Span], outline: &[Point]) {
+    let mut extents: HashMap<i32, (i32, i32)> = HashMap::new();
+    for point in outline {
+        let extent = extents.entry(point.y).or_insert((point.x, point.x));
+        *extent = (extent.0.min(point.x), extent.1.max(point.x));
+    }
+
+    assert_eq!(spans.len(), extents.len());
+    for pair in spans.windows(2) {
+        assert_eq!(pair[0].y + 1, pair[1].y);
+    }
+    for span in spans {
+        assert_eq!(Some(&(span.x_start, span.x_end)), extents.get(&span.y), "row {}", span.y);
+    }
+}
+
+#[test]
+fn circle_spans_fill_the_outline() {
+    for radius in (0..100).chain((100..3000).step_by(37)) {
+        let circle = Circle::new(Point::new(7, -11), radius);
+
+        assert_spans_fill(&calc_circle_spans(circle), &calc_circle_points(circle));
+    }
+}
+
+#[test]
+fn ellipse_spans_fill_the_outline() {
+    for rx in 0..40 {
+        for ry in 0..40 {
+            let ellipse = Ellipse::new(Point::new(-3, 5), rx, ry);
+
+            assert_spans_fill(&calc_ellipse_spans(ellipse), &calc_ellipse_points(ellipse));
+        }
+    }
+}
+
+#[test]
+fn every_pixel_is_covered_once() {
+    let spans = calc_circle_spans(Circle::new(Point::new(0, 0), 30));
+    let pixels: Vec<Point> = spans
+        .iter()
+        .flat_map(|span| (span.x_start..=span.x_end).map(move |x| Point::new(x, span.y)))
+        .collect();
+    let unique: HashSet<&Point> = pixels.iter().collect();
+
+    assert_eq!(unique.len(), pixels.len());
+    assert_eq!(spans.iter().map(|span| span.len() as usize).sum::<usize>(), pixels.len());
+}
+
+#[test]
+fn negative_radii_have_no_spans() {
+    assert!(calc_circle_spans(Circle::new(Point::new(0, 0), -1)).is_empty());
+    assert!(calc_ellipse_spans(Ellipse::new(Point::new(0, 0), 4, -1)).is_empty());
+    assert!(calc_ellipse_spans(Ellipse::new(Point::new(0, 0), -4, 1)).is_empty());
+}
+
+#[test]
+fn spans_stay_within_i32() {
+    let spans = calc_circle_spans(Circle::new(Point::new(i32::MAX - 2, i32::MIN + 1), 5));
+
+    assert_eq!(spans.len(), 7);
+    assert_eq!(spans[0].y, i32::MIN);
+    assert!(spans.iter().all(|span| span.x_end == i32::MAX));
+}
+
+#[test]
+fn rows_of_huge_discs_are_calculated_on_their_own() {
+    let circle = Circle::new(Point::new(0, 0), i32::MAX);
+
+    let top = calc_circle_spans_between(circle, i32::MAX - 2, i32::MAX);
+    let half_widths: Vec<(i32, i32)> = top.iter().map(|span| (span.y, span.x_end)).collect();
+    assert_eq!(half_widths, vec![(i32::MAX - 2, 103621), (i32::MAX - 1, 80264), (i32::MAX, 46340)]);
+
+    let middle = calc_circle_spans_between(circle, -1, 1);
+    assert_eq!(middle.len(), 3);
+    assert!(middle.iter().all(|span| span.x_start == -i32::MAX && span.x_end == i32::MAX));
+
+    let circle = Circle::new(Point::new(7, -11), 250);
+    let rows: Vec<Span> = calc_circle_spans(circle).into_iter().filter(|span| (-100..=20).contains(&span.y)).collect();
+    assert_eq!(calc_circle_spans_between(circle, -100, 20), rows);
+}
+
+#[test]
+fn only_the_rows_on_the_canvas_are_filled() {
+    // Calculating every row of the disc would take billions of spans.
+    let mut canvas = FrameBuffer::new(64, 48);
+    fill_circle(Circle::new(Point::new(0, 0), i32::MAX), RED, &mut canvas);
+    assert!(canvas.pixels().iter().all(|&pixel| pixel == RED));
+
+    let mut canvas = FrameBuffer::new(64, 48);
+    fill_ellipse(Ellipse::new(Point::new(0, 0), 1 << 20, 1 << 16), GREEN, &mut canvas);
+    assert!(canvas.pixels().iter().all(|&pixel| pixel == GREEN));
+}
+
+#[test]
+fn filled_discs_and_ellipses() {
+    let mut canvas = FrameBuffer::new(128, 128);
+
+    fill_circle(Circle::new(Point::new(-32, 32), 25), GREEN, &mut canvas);
+    fill_circle(Circle::new(Point::new(32, 32), 3), RED, &mut canvas);
+    fill_ellipse(Ellipse::new(Point::new(-32, -32), 28, 12), RED, &mut canvas);
+    fill_ellipse(Ellipse::new(Point::new(32, -32), 8, 28), GREEN, &mut canvas);
+    // Mostly off the canvas.
+    fill_circle(Circle::new(Point::new(70, 0), 20), GREEN, &mut canvas);
+
+    assert_golden("filled_discs_and_ellipses", &canvas);
+}
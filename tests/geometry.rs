@@ -0,0 +1,12 @@
+use graphics_rs::geometry::{Point, Rect};
+
+#[test]
+fn rectangles_from_the_center_have_the_size_given() {
+    for &(width, height) in &[(250, 200), (51, 41), (1, 0), (0, 7), (-9, 4)] {
+        let rect = Rect::from_center(Point::new(13, -8), width, height);
+        assert_eq!((rect.width(), rect.height()), (width.abs(), height.abs()), "{} by {}", width, height);
+    }
+
+    assert_eq!(Rect::from_center(Point::new(0, 0), 250, 200), Rect::new(Point::new(-125, -100), Point::new(125, 100)));
+    assert_eq!(Rect::from_center(Point::new(0, 0), 51, 41), Rect::new(Point::new(-25, -20), Point::new(26, 21)));
+}
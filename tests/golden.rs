@@ -0,0 +1,89 @@
+//! Renders the basic line and circle drawings into memory and compares the
+//! result with the reference images in `tests/golden`. The other test files
+//! compare their own drawings the same way, with `common::assert_golden`.
+//!
+//! Set `UPDATE_GOLDEN=1` and run all of the tests to regenerate the references
+//! after an intended change in the output.
+
+mod common;
+
+use graphics_rs::canvas::{Canvas, FrameBuffer};
+use graphics_rs::dash::DashPattern;
+use graphics_rs::dda_line::draw_line_dda;
+use graphics_rs::geometry::{Circle, Line, Point};
+use graphics_rs::midpoint_circle::{draw_8way_axis, draw_circle, draw_flower, Outline};
+use graphics_rs::midpoint_line::{draw_center_axis, draw_line};
+use graphics_rs::viewport::Viewport;
+use image::Rgba;
+
+use common::{assert_golden, GREEN, RED};
+
+/// In screen coordinates, with the origin at the top left.
+#[test]
+fn dda_lines() {
+    let mut buffer = FrameBuffer::new(64, 64);
+    let mut canvas = Viewport::screen().canvas(&mut buffer);
+
+    draw_line_dda(Point::new(2, 2), Point::new(60, 20), RED, &mut canvas);
+    draw_line_dda(Point::new(4, 60), Point::new(24, 6), RED, &mut canvas);
+    draw_line_dda(Point::new(60, 30), Point::new(10, 50), RED, &mut canvas);
+    draw_line_dda(Point::new(50, 62), Point::new(40, 3), RED, &mut canvas);
+
+    assert_golden("dda_lines", &buffer);
+}
+
+/// One line per zone, in the world coordinates of the midpoint binary.
+#[test]
+fn midpoint_line_zones() {
+    let ends = [
+        (Point::new(10, 10), Point::new(60, 50)),       // 0
+        (Point::new(10, 10), Point::new(40, 100)),      // 1
+        (Point::new(-10, 10), Point::new(-40, 100)),    // 2
+        (Point::new(-30, 10), Point::new(-100, 40)),    // 3
+        (Point::new(-30, -10), Point::new(-100, -40)),  // 4
+        (Point::new(-10, -10), Point::new(-40, -100)),  // 5
+        (Point::new(10, -10), Point::new(40, -100)),    // 6
+        (Point::new(10, -10), Point::new(60, -50)),     // 7
+    ];
+
+    for (zone, (start, end)) in ends.iter().enumerate() {
+        let line = Line::new(*start, *end);
+        assert_eq!(line.zone().name() as usize, zone);
+
+        let mut canvas = FrameBuffer::new(256, 256);
+        draw_center_axis(&DashPattern::solid(), RED, &mut canvas);
+        draw_line(line, GREEN, &mut canvas);
+
+        assert_golden(&format!("midpoint_line_zone_{}", zone), &canvas);
+    }
+}
+
+#[test]
+fn midpoint_circles() {
+    let mut canvas = FrameBuffer::new(128, 128);
+
+    draw_circle(Circle::new(Point::new(0, 0), 0), GREEN, &mut canvas);
+    draw_circle(Circle::new(Point::new(0, 0), 5), GREEN, &mut canvas);
+    draw_circle(Circle::new(Point::new(-20, 20), 17), GREEN, &mut canvas);
+    draw_circle(Circle::new(Point::new(10, -15), 40), GREEN, &mut canvas);
+
+    assert_golden("midpoint_circles", &canvas);
+}
+
+#[test]
+fn flower() {
+    let mut canvas = FrameBuffer::new(256, 256);
+    draw_8way_axis(&DashPattern::solid(), RED, &mut canvas);
+    draw_flower(Point::new(0, 0), 100, Outline::Aliased, GREEN, &mut canvas);
+
+    assert_golden("flower", &canvas);
+}
+
+#[test]
+fn anti_aliased_flower() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    canvas.clear(Rgba([255, 255, 255, 255]));
+    draw_flower(Point::new(0, 0), 50, Outline::AntiAliased, Rgba([0, 0, 0, 255]), &mut canvas);
+
+    assert_golden("anti_aliased_flower", &canvas);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Point, PointF};
+use graphics_rs::line_compare::{compare_lines, draw_comparison};
+use graphics_rs::viewport::Viewport;
+
+use common::assert_golden;
+
+#[test]
+fn integer_end_points_agree() {
+    let comparison = compare_lines(Point::new(-3, 4), Point::new(25, -9));
+
+    assert!(comparison.agrees());
+    assert_eq!(comparison.dda_steps(), 29);
+    assert_eq!(comparison.midpoint_steps(), 29);
+    assert!((comparison.dda_max_error - comparison.midpoint_max_error).abs() < 1e-12);
+}
+
+#[test]
+fn sub_pixel_end_points_disagree() {
+    let comparison = compare_lines(PointF::new(0.0, 0.4), PointF::new(10.0, 5.4));
+
+    assert!(!comparison.agrees());
+    assert_eq!(comparison.only_dda.len(), comparison.only_midpoint.len());
+    assert!(comparison.dda_max_error <= 0.5);
+    assert!(comparison.midpoint_max_error > comparison.dda_max_error);
+}
+
+#[test]
+fn single_pixel_error_is_the_distance_to_the_point() {
+    let comparison = compare_lines(PointF::new(1.3, 1.4), PointF::new(1.3, 1.4));
+
+    assert!(comparison.agrees());
+    assert!((comparison.dda_max_error - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn diff_image() {
+    let mut buffer = FrameBuffer::new(64, 64);
+    let mut canvas = Viewport::screen().canvas(&mut buffer);
+    draw_comparison(&compare_lines(PointF::new(2.0, 3.4), PointF::new(60.4, 40.7)), &mut canvas);
+
+    assert_golden("line_compare", &buffer);
+}
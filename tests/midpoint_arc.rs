@@ -0,0 +1,102 @@
+mod common;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Circle, Point};
+use graphics_rs::midpoint_circle::{calc_arc_points, calc_circle_octants, calc_circle_points, draw_arc, draw_pie};
+
+use common::{assert_golden, GREEN, RED};
+
+fn angle_of(point: Point, center: Point) -> f64 {
+    f64::from(point.y - center.y).atan2(f64::from(point.x - center.x)).to_degrees()
+}
+
+/// The smallest difference between two angles, in degrees.
+fn angle_distance(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+#[test]
+fn quarter_arc_is_the_first_two_octants() {
+    let circle = Circle::new(Point::new(4, -7), 30);
+    let octants = calc_circle_octants(circle);
+    let mut expected = [octants[0].clone(), octants[1].clone()].concat();
+    expected.push(Point::new(4, -7 + 30));
+
+    assert_eq!(calc_arc_points(circle, 0.0, 90.0), expected);
+}
+
+#[test]
+fn full_turn_is_the_whole_circle() {
+    let circle = Circle::new(Point::new(0, 0), 25);
+
+    assert_eq!(calc_arc_points(circle, 0.0, 360.0), calc_circle_points(circle));
+    assert_eq!(calc_arc_points(circle, -90.0, 400.0), calc_circle_points(circle));
+}
+
+#[test]
+fn arcs_end_at_the_closest_pixels() {
+    let center = Point::new(-3, 5);
+    for radius in 1..60 {
+        let circle = Circle::new(center, radius);
+        let all = calc_circle_points(circle);
+        let closest = |angle: f64| {
+            all.iter()
+                .map(|point| angle_distance(angle_of(*point, center), angle))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        for &(start, end) in &[(10.0, 80.0), (-30.0, 200.0), (350.0, 10.0), (123.4, 123.4), (95.0, 85.0)] {
+            let arc = calc_arc_points(circle, start, end);
+            let first = angle_of(arc[0], center);
+            let last = angle_of(arc[arc.len() - 1], center);
+
+            assert!(angle_distance(first, start) <= closest(start) + 1e-9, "radius {} starts at {:?}", radius, arc[0]);
+            assert!(angle_distance(last, end) <= closest(end) + 1e-9, "radius {} ends at {:?}", radius, arc[arc.len() - 1]);
+        }
+    }
+}
+
+#[test]
+fn arcs_follow_the_circle() {
+    for radius in 1..60 {
+        let circle = Circle::new(Point::new(0, 0), radius);
+        let all = calc_circle_points(circle);
+
+        for &(start, end) in &[(0.0, 45.0), (30.0, 300.0), (-100.0, -10.0), (270.0, 90.0), (0.0, 359.0)] {
+            let arc = calc_arc_points(circle, start, end);
+
+            assert!(arc.iter().all(|point| all.contains(point)));
+            for pair in arc.windows(2) {
+                let step = pair[1] - pair[0];
+                assert!(step.x.abs() <= 1 && step.y.abs() <= 1, "radius {} jumps at {:?}", radius, pair);
+            }
+        }
+    }
+}
+
+#[test]
+fn start_and_end_at_the_same_angle_is_a_single_pixel() {
+    let arc = calc_arc_points(Circle::new(Point::new(0, 0), 40), 90.0, 90.0);
+
+    assert_eq!(arc, vec![Point::new(0, 40)]);
+}
+
+#[test]
+fn invalid_arcs_have_no_points() {
+    assert!(calc_arc_points(Circle::new(Point::new(0, 0), -5), 0.0, 90.0).is_empty());
+    assert!(calc_arc_points(Circle::new(Point::new(0, 0), 5), f64::NAN, 90.0).is_empty());
+    assert!(calc_arc_points(Circle::new(Point::new(0, 0), 5), 0.0, f64::INFINITY).is_empty());
+}
+
+#[test]
+fn arcs_and_pies() {
+    let mut canvas = FrameBuffer::new(128, 128);
+
+    draw_arc(Point::new(-30, 30), 25, 30.0, 240.0, GREEN, &mut canvas);
+    draw_arc(Point::new(30, 30), 25, 300.0, 60.0, RED, &mut canvas);
+    draw_pie(Point::new(-30, -30), 25, 0.0, 90.0, GREEN, &mut canvas);
+    draw_pie(Point::new(30, -30), 25, 100.0, 395.0, RED, &mut canvas);
+
+    assert_golden("arcs_and_pies", &canvas);
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use graphics_rs::canvas::{Canvas, FrameBuffer};
+use graphics_rs::geometry::{Circle, Point};
+use std::collections::HashSet;
+
+use graphics_rs::midpoint_circle::{calc_circle_octants, calc_circle_points, calc_wu_circle_points, draw_circle};
+
+use common::GREEN;
+
+#[test]
+fn large_radius_stays_on_the_circle() {
+    let radius = 200_000;
+    let circle = Circle::new(Point::new(-1_000_000, 3_000_000), radius);
+
+    for point in calc_circle_points(circle) {
+        let (x, y) = (i64::from(point.x - circle.center.x), i64::from(point.y - circle.center.y));
+        let distance = ((x * x + y * y) as f64).sqrt();
+        assert!((distance - f64::from(radius)).abs() < 1.0, "{:?} is off the circle", point);
+    }
+}
+
+#[test]
+fn points_outside_of_i32_are_left_out() {
+    let circle = Circle::new(Point::new(i32::MAX - 5, 0), 10);
+    let points = calc_circle_points(circle);
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|point| point.x >= i32::MAX - 15));
+    assert!(points.contains(&Point::new(i32::MAX - 15, 0)));
+}
+
+#[test]
+fn negative_radius_has_no_points() {
+    assert!(calc_circle_points(Circle::new(Point::new(0, 0), -4)).is_empty());
+}
+
+#[test]
+fn circles_are_clipped_to_the_canvas() {
+    let mut canvas = FrameBuffer::new(64, 64);
+
+    // Larger than the canvas, crossing all four edges.
+    draw_circle(Circle::new(Point::new(0, 0), 40), GREEN, &mut canvas);
+    // Entirely off the canvas.
+    draw_circle(Circle::new(Point::new(5_000, -9_000), 1_000), GREEN, &mut canvas);
+    draw_circle(Circle::new(Point::new(i32::MIN, i32::MAX), 100), GREEN, &mut canvas);
+
+    let drawn: Vec<(u32, u32)> = (0..64)
+        .flat_map(|y| (0..64).map(move |x| (x, y)))
+        .filter(|&(x, y)| canvas.get_pixel(x, y) == GREEN)
+        .collect();
+
+    assert!(!drawn.is_empty());
+    // Every pixel on the canvas belongs to the large circle.
+    assert!(drawn.iter().all(|&(x, y)| {
+        let (dx, dy) = (x as i64 - 32, 32 - y as i64);
+        (dx * dx + dy * dy - 40 * 40).abs() <= 2 * 40
+    }));
+}
+
+#[test]
+fn points_are_not_repeated() {
+    for radius in 0..80 {
+        let points = calc_circle_points(Circle::new(Point::new(3, -2), radius));
+        let unique: HashSet<&Point> = points.iter().collect();
+
+        assert_eq!(unique.len(), points.len(), "radius {}", radius);
+    }
+}
+
+#[test]
+fn points_follow_the_perimeter() {
+    for radius in 1..80 {
+        let points = calc_circle_points(Circle::new(Point::new(0, 0), radius));
+
+        assert_eq!(points[0], Point::new(radius, 0));
+        let closing = [points[points.len() - 1], points[0]];
+        for pair in points.windows(2).chain(std::iter::once(&closing[..])) {
+            let step = pair[1] - pair[0];
+            assert!(step.x.abs() <= 1 && step.y.abs() <= 1, "radius {} jumps at {:?}", radius, pair);
+
+            // Counter-clockwise: the cross product of consecutive points is
+            // never negative.
+            let turn = i64::from(pair[0].x) * i64::from(pair[1].y) - i64::from(pair[0].y) * i64::from(pair[1].x);
+            assert!(turn >= 0, "radius {} turns back at {:?}", radius, pair);
+        }
+    }
+}
+
+#[test]
+fn octants_cover_their_angles() {
+    let radius = 57;
+    let octants = calc_circle_octants(Circle::new(Point::new(0, 0), radius));
+
+    for (n, octant) in octants.iter().enumerate() {
+        assert!(!octant.is_empty());
+        for point in octant {
+            let angle = f64::from(point.y).atan2(f64::from(point.x)).to_degrees().rem_euclid(360.0);
+            let from = n as f64 * 45.0;
+            assert!(angle >= from - 1.0 && angle <= from + 46.0, "{:?} at {}° in octant {}", point, angle, n);
+        }
+    }
+
+    assert_eq!(octants.concat(), calc_circle_points(Circle::new(Point::new(0, 0), radius)));
+}
+
+#[test]
+fn tiny_circles() {
+    assert_eq!(calc_circle_points(Circle::new(Point::new(2, 2), 0)), vec![Point::new(2, 2)]);
+    assert_eq!(
+        calc_circle_points(Circle::new(Point::new(0, 0), 1)),
+        vec![Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0), Point::new(0, -1)]
+    );
+}
+
+#[test]
+fn wu_circles_split_every_column() {
+    for radius in 1..60 {
+        let points = calc_wu_circle_points(Circle::new(Point::new(0, 0), radius));
+        let unique: HashSet<Point> = points.iter().map(|&(point, _)| point).collect();
+        assert_eq!(unique.len(), points.len(), "radius {}", radius);
+
+        for &(point, coverage) in &points {
+            assert!(coverage > 0.0 && coverage <= 1.0);
+            // Every mirror image is there as well.
+            for &(x, y) in &[(point.y, point.x), (-point.x, point.y), (point.x, -point.y)] {
+                assert!(unique.contains(&Point::new(x, y)), "radius {} misses the mirror of {:?}", radius, point);
+            }
+        }
+
+        // The columns of the second octant share a coverage of 1, centered on
+        // the exact circle.
+        for x in (0..).take_while(|x| 2 * x * x <= radius * radius) {
+            let column: Vec<(Point, f64)> = points.iter().copied().filter(|&(p, _)| p.x == x && p.y >= x).collect();
+            let total: f64 = column.iter().map(|&(_, coverage)| coverage).sum();
+            let center: f64 = column.iter().map(|&(p, coverage)| f64::from(p.y) * coverage).sum();
+            let exact = f64::from(radius * radius - x * x).sqrt();
+            assert!((total - 1.0).abs() < 1e-9, "radius {} column {}", radius, x);
+            assert!((center - exact).abs() < 1e-9, "radius {} column {}", radius, x);
+        }
+    }
+}
+
+#[test]
+fn tiny_wu_circles() {
+    assert_eq!(calc_wu_circle_points(Circle::new(Point::new(2, 2), 0)), vec![(Point::new(2, 2), 1.0)]);
+    assert!(calc_wu_circle_points(Circle::new(Point::new(0, 0), -3)).is_empty());
+    assert_eq!(
+        calc_wu_circle_points(Circle::new(Point::new(0, 0), 1)),
+        vec![(Point::new(1, 0), 1.0), (Point::new(0, 1), 1.0), (Point::new(-1, 0), 1.0), (Point::new(0, -1), 1.0)]
+    );
+}
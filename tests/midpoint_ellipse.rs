@@ -0,0 +1,162 @@
+mod common;
+
+use std::collections::HashSet;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::dash::DashPattern;
+use graphics_rs::geometry::{Circle, Ellipse, Point};
+use graphics_rs::midpoint_circle::calc_wu_circle_points;
+use graphics_rs::midpoint_ellipse::{calc_ellipse_points, calc_wu_ellipse_points, draw_ellipse};
+use graphics_rs::midpoint_line::draw_center_axis;
+
+use common::{assert_golden, GREEN, RED};
+
+fn radii() -> impl Iterator<Item = (i32, i32)> {
+    (1..40).flat_map(|rx| (1..40).map(move |ry| (rx, ry)))
+}
+
+/// How far `point` is from the ellipse, along whichever axis it is closer.
+fn error(point: Point, rx: i32, ry: i32) -> f64 {
+    let (x, y) = (f64::from(point.x.abs()), f64::from(point.y.abs()));
+    let (rx, ry) = (f64::from(rx), f64::from(ry));
+
+    let along_x = if y <= ry { (x - rx * (1.0 - (y / ry).powi(2)).sqrt()).abs() } else { f64::INFINITY };
+    let along_y = if x <= rx { (y - ry * (1.0 - (x / rx).powi(2)).sqrt()).abs() } else { f64::INFINITY };
+    along_x.min(along_y)
+}
+
+#[test]
+fn points_are_close_to_the_ellipse() {
+    for (rx, ry) in radii() {
+        for point in calc_ellipse_points(Ellipse::new(Point::new(0, 0), rx, ry)) {
+            assert!(error(point, rx, ry) < 1.0, "{:?} is off the {}x{} ellipse", point, rx, ry);
+        }
+    }
+}
+
+#[test]
+fn points_are_not_repeated_and_follow_the_perimeter() {
+    for (rx, ry) in radii() {
+        let points = calc_ellipse_points(Ellipse::new(Point::new(0, 0), rx, ry));
+        let unique: HashSet<&Point> = points.iter().collect();
+        assert_eq!(unique.len(), points.len(), "{}x{}", rx, ry);
+
+        assert_eq!(points[0], Point::new(rx, 0));
+        assert_neighbours(&points, rx, ry);
+    }
+}
+
+/// Checks that every point of the `rx`x`ry` ellipse is a neighbour of the one
+/// before it, the first one of the last one.
+fn assert_neighbours(points: &[Point], rx: i32, ry: i32) {
+    let closing = [points[points.len() - 1], points[0]];
+    for pair in points.windows(2).chain(std::iter::once(&closing[..])) {
+        let step = pair[1] - pair[0];
+        assert!(step.x.abs() <= 1 && step.y.abs() <= 1, "{}x{} jumps at {:?}", rx, ry, pair);
+    }
+}
+
+#[test]
+fn very_flat_ellipses_have_no_gaps() {
+    for ry in 1..=2 {
+        for rx in (40..2000).step_by(13) {
+            let points = calc_ellipse_points(Ellipse::new(Point::new(-7, 3), rx, ry));
+            assert_neighbours(&points, rx, ry);
+
+            // Only the ends are on the axis, the rows above and below it
+            // reach out to them.
+            let ends: Vec<&Point> = points.iter().filter(|point| point.y == 3).collect();
+            assert_eq!(ends, vec![&Point::new(rx - 7, 3), &Point::new(-rx - 7, 3)], "{}x{}", rx, ry);
+        }
+    }
+}
+
+#[test]
+fn points_are_symmetric() {
+    for (rx, ry) in radii() {
+        let points: HashSet<Point> = calc_ellipse_points(Ellipse::new(Point::new(0, 0), rx, ry)).into_iter().collect();
+        for point in &points {
+            assert!(points.contains(&Point::new(-point.x, point.y)));
+            assert!(points.contains(&Point::new(point.x, -point.y)));
+        }
+    }
+}
+
+#[test]
+fn flat_ellipses_are_segments() {
+    let horizontal = calc_ellipse_points(Ellipse::new(Point::new(1, 1), 2, 0));
+    assert_eq!(horizontal, vec![Point::new(3, 1), Point::new(2, 1), Point::new(1, 1), Point::new(0, 1), Point::new(-1, 1)]);
+
+    let vertical = calc_ellipse_points(Ellipse::new(Point::new(0, 0), 0, 1));
+    assert_eq!(vertical, vec![Point::new(0, 1), Point::new(0, 0), Point::new(0, -1)]);
+
+    assert_eq!(calc_ellipse_points(Ellipse::new(Point::new(0, 0), 0, 0)), vec![Point::new(0, 0)]);
+    assert!(calc_ellipse_points(Ellipse::new(Point::new(0, 0), -1, 3)).is_empty());
+}
+
+#[test]
+fn large_radii_do_not_overflow() {
+    // 4 * rx² * ry² is above the i64 range for these radii.
+    let (rx, ry) = (50_000, 40_000);
+    let points = calc_ellipse_points(Ellipse::new(Point::new(0, 0), rx, ry));
+
+    assert_eq!(points[0], Point::new(rx, 0));
+    assert!(points.contains(&Point::new(0, ry)));
+    for point in points.iter().step_by(997) {
+        assert!(error(*point, rx, ry) < 1.0, "{:?} is off the ellipse", point);
+    }
+}
+
+#[test]
+fn ellipses() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    draw_center_axis(&DashPattern::solid(), RED, &mut canvas);
+    draw_ellipse(Ellipse::new(Point::new(0, 0), 60, 25), GREEN, &mut canvas);
+    draw_ellipse(Ellipse::new(Point::new(-10, 12), 9, 30), GREEN, &mut canvas);
+    draw_ellipse(Ellipse::new(Point::new(30, -40), 20, 20), GREEN, &mut canvas);
+
+    assert_golden("midpoint_ellipses", &canvas);
+}
+
+#[test]
+fn wu_ellipses_are_symmetric() {
+    for (rx, ry) in radii() {
+        let points = calc_wu_ellipse_points(Ellipse::new(Point::new(0, 0), rx, ry));
+        let unique: HashSet<Point> = points.iter().map(|&(point, _)| point).collect();
+        assert_eq!(unique.len(), points.len(), "{}x{}", rx, ry);
+
+        for &(point, coverage) in &points {
+            assert!(coverage > 0.0 && coverage <= 1.0);
+            assert!(unique.contains(&Point::new(-point.x, point.y)) && unique.contains(&Point::new(point.x, -point.y)));
+
+            // Within a pixel of the exact ellipse.
+            let (x, y) = (f64::from(point.x) / f64::from(rx), f64::from(point.y) / f64::from(ry));
+            let scale = f64::from(rx.min(ry));
+            assert!(((x * x + y * y).sqrt() - 1.0).abs() * scale < 1.5, "{}x{} has {:?}", rx, ry, point);
+        }
+    }
+}
+
+#[test]
+fn wu_ellipse_of_equal_radii_is_the_wu_circle() {
+    for radius in 1..40 {
+        let mut ellipse = calc_wu_ellipse_points(Ellipse::new(Point::new(4, -7), radius, radius));
+        let mut circle = calc_wu_circle_points(Circle::new(Point::new(4, -7), radius));
+
+        let key = |&(p, _): &(Point, f64)| (p.x, p.y);
+        ellipse.sort_by_key(key);
+        circle.sort_by_key(key);
+        assert_eq!(ellipse.len(), circle.len(), "radius {}", radius);
+        for (a, b) in ellipse.iter().zip(&circle) {
+            assert_eq!(a.0, b.0);
+            assert!((a.1 - b.1).abs() < 1e-9, "radius {} at {:?}", radius, a.0);
+        }
+    }
+}
+
+#[test]
+fn flat_wu_ellipses_are_segments() {
+    let points = calc_wu_ellipse_points(Ellipse::new(Point::new(0, 0), 3, 0));
+    assert_eq!(points.len(), 7);
+    assert!(points.iter().all(|&(point, coverage)| point.y == 0 && coverage == 1.0));
+}
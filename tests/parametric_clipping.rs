@@ -0,0 +1,175 @@
+use graphics_rs::cohen_sutherland;
+use graphics_rs::cyrus_beck;
+use graphics_rs::geometry::{Line, Point, Polygon, Rect};
+use graphics_rs::liang_barsky::{self, ClipParameters, Parameter};
+
+fn line(x1: i32, y1: i32, x2: i32, y2: i32) -> Line {
+    Line::new(Point::new(x1, y1), Point::new(x2, y2))
+}
+
+/// Lines between the points of a grid around the window, through it, past it
+/// and along its sides.
+fn lines() -> Vec<Line> {
+    let coordinates = [-40, -20, -10, -3, 0, 7, 20, 33, 50];
+    let points: Vec<Point> = coordinates
+        .iter()
+        .flat_map(|&x| coordinates.iter().map(move |&y| Point::new(x, y)))
+        .collect();
+    points
+        .iter()
+        .flat_map(|&start| points.iter().map(move |&end| Line::new(start, end)))
+        .collect()
+}
+
+fn window() -> Rect {
+    Rect::new(Point::new(-10, -3), Point::new(20, 33))
+}
+
+/// A square of side 60 around the origin, turned by 30°.
+fn rotated_square() -> Polygon {
+    Polygon::from(vec![
+        Point::new(26, -15),
+        Point::new(41, 11),
+        Point::new(15, 26),
+        Point::new(0, 0),
+    ])
+}
+
+#[test]
+fn lines_inside_keep_the_whole_range() {
+    let inside = line(-10, -3, 20, 33);
+    let expected = Some(ClipParameters { t_enter: Parameter::START, t_exit: Parameter::END });
+
+    assert_eq!(liang_barsky::clip_parameters(inside, &window()), expected);
+    assert_eq!(cyrus_beck::clip_parameters(inside, &Polygon::from(window())), expected);
+    assert_eq!(liang_barsky::clip_line(inside, &window()), Some(inside));
+}
+
+#[test]
+fn parameters_of_a_crossing_line() {
+    let crossing = line(-30, 0, 30, 0);
+    let parameters = liang_barsky::clip_parameters(crossing, &window()).unwrap();
+
+    assert_eq!(parameters, ClipParameters { t_enter: Parameter::new(20, 60), t_exit: Parameter::new(5, 6) });
+    assert_eq!(parameters.apply(crossing), line(-10, 0, 20, 0));
+}
+
+#[test]
+fn liang_barsky_agrees_with_cohen_sutherland() {
+    for line in lines() {
+        let liang_barsky = liang_barsky::clip_line(line, &window());
+        let cohen_sutherland = cohen_sutherland::clip_line(line, &window());
+
+        assert_eq!(liang_barsky, cohen_sutherland, "{:?}", line);
+    }
+}
+
+#[test]
+fn cyrus_beck_on_a_rectangle_is_liang_barsky() {
+    let rect = window();
+    let counter_clockwise = Polygon::from(rect);
+    let clockwise = Polygon::new(counter_clockwise.vertices.iter().rev().copied().collect());
+
+    for line in lines() {
+        let expected = liang_barsky::clip_parameters(line, &rect);
+
+        assert_eq!(cyrus_beck::clip_parameters(line, &counter_clockwise), expected, "{:?}", line);
+        assert_eq!(cyrus_beck::clip_parameters(line, &clockwise), expected, "{:?}", line);
+    }
+}
+
+#[test]
+fn cyrus_beck_clips_to_rotated_windows() {
+    let square = rotated_square();
+    let inside = |x: f64, y: f64| {
+        square.edges().all(|edge| {
+            let (ex, ey) = (f64::from(edge.end.x - edge.start.x), f64::from(edge.end.y - edge.start.y));
+            let (px, py) = (x - f64::from(edge.start.x), y - f64::from(edge.start.y));
+            (ex * py - ey * px) / ex.hypot(ey) >= -1e-9
+        })
+    };
+
+    let mut clipped = 0;
+    for line in lines() {
+        match cyrus_beck::clip_parameters(line, &square) {
+            Some(parameters) => {
+                clipped += 1;
+                let (t_enter, t_exit) = (parameters.t_enter.value(), parameters.t_exit.value());
+                for &t in &[t_enter, t_exit, (t_enter + t_exit) / 2.0] {
+                    let point = line.point_at(t);
+                    assert!(inside(point.x, point.y), "{:?} leaves the window at {}", line, t);
+                }
+            }
+            None => {
+                // No part of the line is inside.
+                assert!((0..=100).all(|i| {
+                    let point = line.point_at(f64::from(i) / 100.0);
+                    !inside(point.x, point.y)
+                }), "{:?} was rejected", line);
+            }
+        }
+    }
+    assert!(clipped > 0);
+}
+
+#[test]
+fn lines_outside_are_rejected() {
+    let square = rotated_square();
+
+    // Parallel to an edge, just outside of it.
+    assert_eq!(cyrus_beck::clip_parameters(line(-1, 1, 14, 27), &square), None);
+    assert_eq!(liang_barsky::clip_parameters(line(-11, -40, -11, 40), &window()), None);
+    assert_eq!(liang_barsky::clip_parameters(line(21, 40, 40, 0), &window()), None);
+}
+
+#[test]
+fn degenerate_windows_clip_everything() {
+    let segment = Polygon::from(vec![Point::new(0, 0), Point::new(10, 10)]);
+    let flat = Polygon::from(vec![Point::new(0, 0), Point::new(10, 10), Point::new(20, 20)]);
+
+    assert_eq!(cyrus_beck::clip_parameters(line(0, 0, 10, 10), &segment), None);
+    assert_eq!(cyrus_beck::clip_parameters(line(0, 0, 10, 10), &flat), None);
+}
+
+#[test]
+fn single_points() {
+    let square = rotated_square();
+
+    assert!(cyrus_beck::clip_parameters(line(20, 5, 20, 5), &square).is_some());
+    assert!(cyrus_beck::clip_parameters(line(0, 20, 0, 20), &square).is_none());
+    assert!(liang_barsky::clip_parameters(line(0, 0, 0, 0), &window()).is_some());
+    assert!(liang_barsky::clip_parameters(line(-11, 0, -11, 0), &window()).is_none());
+}
+
+#[test]
+fn extreme_coordinates_do_not_overflow() {
+    let huge = Polygon::from(vec![
+        Point::new(i32::MIN, i32::MIN),
+        Point::new(i32::MAX, i32::MIN),
+        Point::new(i32::MAX, i32::MAX),
+        Point::new(i32::MIN, i32::MAX),
+    ]);
+    let diagonal = line(i32::MIN, i32::MIN, i32::MAX, i32::MAX);
+
+    assert_eq!(cyrus_beck::clip_line(diagonal, &huge), Some(diagonal));
+    assert_eq!(
+        liang_barsky::clip_line(diagonal, &Rect::new(Point::new(-5, -5), Point::new(5, 5))),
+        Some(line(-5, -5, 5, 5))
+    );
+}
+
+#[test]
+fn parameters_are_exact_fractions() {
+    assert_eq!(Parameter::new(2, 4), Parameter::new(-1, -2));
+    assert!(Parameter::new(1, 3) < Parameter::new(1, 2) && Parameter::new(-1, 2) < Parameter::START);
+
+    // Too close for an `f64`, and too large to multiply crosswise.
+    let big = i128::from(i64::MAX) << 40;
+    assert!(Parameter::new(big - 1, big) < Parameter::new(big, big + 1));
+    assert_eq!(Parameter::new(big - 1, big).value(), Parameter::new(big, big + 1).value());
+
+    // Halves are rounded away from zero.
+    let segment = line(-3, -3, 0, 2);
+    assert_eq!(Parameter::new(1, 2).pixel_on(segment), Point::new(-2, -1));
+    assert_eq!(Parameter::new(1, 6).pixel_on(segment), Point::new(-3, -2));
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use std::collections::HashSet;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Point, Polygon, Rect};
+use graphics_rs::midpoint_line::draw_line;
+use graphics_rs::polygon_fill::{calc_polygon_spans, fill_polygon, FillRule};
+use graphics_rs::sutherland_hodgman;
+use graphics_rs::weiler_atherton;
+
+use common::{assert_golden, GREEN, RED};
+
+fn polygon(points: &[(i32, i32)]) -> Polygon {
+    Polygon::new(points.iter().map(|&point| Point::from(point)).collect())
+}
+
+fn filled(polygons: &[Polygon]) -> HashSet<Point> {
+    polygons
+        .iter()
+        .flat_map(|polygon| calc_polygon_spans(polygon, FillRule::NonZero))
+        .flat_map(|span| (span.x_start..=span.x_end).map(move |x| Point::new(x, span.y)))
+        .collect()
+}
+
+/// A concave window like the letter C, open to the right.
+fn c_shape() -> Polygon {
+    polygon(&[(-40, -40), (40, -40), (40, -20), (-20, -20), (-20, 20), (40, 20), (40, 40), (-40, 40)])
+}
+
+/// A concave five pointed star that does not cross itself.
+fn star() -> Polygon {
+    polygon(&[
+        (0, 50),
+        (-12, 16),
+        (-47, 15),
+        (-19, -6),
+        (-29, -40),
+        (0, -20),
+        (29, -40),
+        (19, -6),
+        (47, 15),
+        (12, 16),
+    ])
+}
+
+/// Checks that `clipped` fills the pixels both `subject` and `window` fill,
+/// give or take the pixels along the border where the vertices were rounded.
+fn assert_fills_intersection(clipped: &[Polygon], subject: &Polygon, window: &Polygon) {
+    let expected: HashSet<Point> = filled(std::slice::from_ref(subject)).intersection(&filled(std::slice::from_ref(window))).copied().collect();
+    let actual = filled(clipped);
+
+    let differing = expected.symmetric_difference(&actual).count();
+    assert!(
+        differing * 20 <= expected.len().max(20),
+        "{} of {} pixels differ",
+        differing,
+        expected.len()
+    );
+}
+
+#[test]
+fn rectangles_clip_to_their_intersection() {
+    let subject = Polygon::from(Rect::new(Point::new(-30, -10), Point::new(10, 30)));
+    let window = Polygon::from(Rect::new(Point::new(-10, -20), Point::new(20, 20)));
+    let expected: HashSet<Point> = Rect::new(Point::new(-10, -10), Point::new(10, 20)).corners().iter().copied().collect();
+
+    let clipped = sutherland_hodgman::clip_polygon(&subject, &window);
+    assert_eq!(clipped.vertices.iter().copied().collect::<HashSet<Point>>(), expected);
+
+    let clipped = weiler_atherton::clip_polygon(&subject, &window);
+    assert_eq!(clipped.len(), 1);
+    assert_eq!(clipped[0].vertices.iter().copied().collect::<HashSet<Point>>(), expected);
+}
+
+#[test]
+fn polygons_inside_and_outside() {
+    let window = Polygon::from(Rect::new(Point::new(-50, -50), Point::new(50, 50)));
+    let inside = polygon(&[(0, 0), (10, 0), (0, 10)]);
+    let outside = polygon(&[(60, 60), (70, 60), (60, 70)]);
+
+    assert_eq!(sutherland_hodgman::clip_polygon(&inside, &window), inside);
+    assert!(sutherland_hodgman::clip_polygon(&outside, &window).vertices.is_empty());
+    assert_eq!(weiler_atherton::clip_polygon(&inside, &window), vec![inside.clone()]);
+    assert!(weiler_atherton::clip_polygon(&outside, &window).is_empty());
+    // The window inside of the polygon.
+    assert_eq!(weiler_atherton::clip_polygon(&window, &inside), vec![inside]);
+}
+
+#[test]
+fn sutherland_hodgman_clips_to_convex_windows() {
+    let square = polygon(&[(35, 0), (0, 35), (-35, 0), (0, -35)]);
+    let clipped = sutherland_hodgman::clip_polygon(&star(), &square);
+
+    assert_fills_intersection(std::slice::from_ref(&clipped), &star(), &square);
+    // Either direction of the window gives the same result.
+    assert_eq!(sutherland_hodgman::clip_polygon(&star(), &square.reversed()), clipped);
+}
+
+#[test]
+fn weiler_atherton_splits_polygons_at_concave_windows() {
+    // Crosses both arms of the C and the gap between them.
+    let bar = Polygon::from(Rect::new(Point::new(0, -30), Point::new(30, 30)));
+    let clipped = weiler_atherton::clip_polygon(&bar, &c_shape());
+
+    assert_eq!(clipped.len(), 2);
+    assert_fills_intersection(&clipped, &bar, &c_shape());
+    for polygon in &clipped {
+        assert!(polygon.signed_area() > 0.0);
+    }
+}
+
+#[test]
+fn weiler_atherton_clips_concave_polygons() {
+    for window in &[c_shape(), c_shape().reversed(), polygon(&[(35, 0), (0, 35), (-35, 0), (0, -35)])] {
+        let clipped = weiler_atherton::clip_polygon(&star(), window);
+
+        assert!(!clipped.is_empty());
+        assert_fills_intersection(&clipped, &star(), window);
+    }
+}
+
+#[test]
+fn shared_edges_and_vertices() {
+    let window = Polygon::from(Rect::new(Point::new(0, 0), Point::new(40, 40)));
+    // Along the bottom edge of the window and through its corner.
+    let subject = polygon(&[(10, 0), (30, 0), (60, 30), (40, 40), (-10, 40)]);
+
+    let clipped = weiler_atherton::clip_polygon(&subject, &window);
+    assert_fills_intersection(&clipped, &subject, &window);
+    assert_fills_intersection(&[sutherland_hodgman::clip_polygon(&subject, &window)], &subject, &window);
+
+    // The same polygon clips to itself.
+    assert_eq!(filled(&weiler_atherton::clip_polygon(&window, &window)), filled(&[window]));
+}
+
+#[test]
+fn windows_without_area_clip_everything() {
+    let flat = polygon(&[(0, 0), (10, 10), (20, 20)]);
+
+    assert!(sutherland_hodgman::clip_polygon(&star(), &flat).vertices.is_empty());
+    assert!(weiler_atherton::clip_polygon(&star(), &flat).is_empty());
+    assert!(weiler_atherton::clip_polygon(&flat, &star()).is_empty());
+}
+
+#[test]
+fn clipped_polygons() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    let offset = |polygon: Polygon, x: i32, y: i32| {
+        Polygon::new(polygon.vertices.into_iter().map(|vertex| vertex + Point::new(x, y)).collect())
+    };
+
+    let square = offset(polygon(&[(25, 0), (0, 25), (-25, 0), (0, -25)]), -32, 32);
+    let clipped = sutherland_hodgman::clip_polygon(&offset(star(), -32, 32), &square);
+    fill_polygon(&clipped, FillRule::NonZero, GREEN, &mut canvas);
+    for edge in square.edges() {
+        draw_line(edge, RED, &mut canvas);
+    }
+
+    let c = offset(c_shape(), 20, -10);
+    for clipped in weiler_atherton::clip_polygon(&offset(star(), 32, -10), &c) {
+        fill_polygon(&clipped, FillRule::NonZero, GREEN, &mut canvas);
+    }
+    for edge in c.edges() {
+        draw_line(edge, RED, &mut canvas);
+    }
+
+    assert_golden("clipped_polygons", &canvas);
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use std::collections::HashSet;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Point, Polygon, Rect, Span};
+use graphics_rs::polygon_fill::{calc_polygon_spans, fill_polygon, FillRule};
+
+use common::{assert_golden, GREEN, RED};
+
+fn pixels(spans: &[Span]) -> Vec<Point> {
+    spans
+        .iter()
+        .flat_map(|span| (span.x_start..=span.x_end).map(move |x| Point::new(x, span.y)))
+        .collect()
+}
+
+fn star(radius: i32) -> Polygon {
+    Polygon::from(vec![
+        Point::new(0, radius),
+        Point::new(-radius * 59 / 100, -radius * 81 / 100),
+        Point::new(radius * 95 / 100, radius * 31 / 100),
+        Point::new(-radius * 95 / 100, radius * 31 / 100),
+        Point::new(radius * 59 / 100, -radius * 81 / 100),
+    ])
+}
+
+#[test]
+fn square_fills_its_area() {
+    let square = Polygon::from(Rect::new(Point::new(0, 0), Point::new(10, 10)));
+    let filled = pixels(&calc_polygon_spans(&square, FillRule::EvenOdd));
+
+    assert_eq!(filled.len(), 100);
+    assert!(filled.iter().all(|point| (0..10).contains(&point.x) && (0..10).contains(&point.y)));
+}
+
+#[test]
+fn polygons_sharing_an_edge_do_not_overlap() {
+    let corners = [Point::new(-7, -3), Point::new(20, 1), Point::new(13, 17), Point::new(-2, 11)];
+    let quad = Polygon::from(corners.to_vec());
+    let halves = [
+        Polygon::from(vec![corners[0], corners[1], corners[2]]),
+        Polygon::from(vec![corners[0], corners[2], corners[3]]),
+    ];
+
+    let whole: HashSet<Point> = pixels(&calc_polygon_spans(&quad, FillRule::EvenOdd)).into_iter().collect();
+    let mut parts = pixels(&calc_polygon_spans(&halves[0], FillRule::EvenOdd));
+    parts.extend(pixels(&calc_polygon_spans(&halves[1], FillRule::EvenOdd)));
+    let unique: HashSet<Point> = parts.iter().copied().collect();
+
+    assert_eq!(unique.len(), parts.len());
+    assert_eq!(unique, whole);
+}
+
+#[test]
+fn fill_rules_differ_in_the_middle_of_a_star() {
+    let star = star(100);
+    let even_odd: HashSet<Point> = pixels(&calc_polygon_spans(&star, FillRule::EvenOdd)).into_iter().collect();
+    let non_zero: HashSet<Point> = pixels(&calc_polygon_spans(&star, FillRule::NonZero)).into_iter().collect();
+
+    assert!(!even_odd.contains(&Point::new(0, 0)));
+    assert!(non_zero.contains(&Point::new(0, 0)));
+    assert!(even_odd.is_subset(&non_zero));
+    // The points of the star are inside either way.
+    assert!(even_odd.contains(&Point::new(0, 90)));
+}
+
+#[test]
+fn both_rules_agree_on_simple_polygons() {
+    // Concave, and walked clockwise.
+    let arrow = Polygon::from(vec![
+        Point::new(0, 0),
+        Point::new(-20, 30),
+        Point::new(40, 0),
+        Point::new(-20, -30),
+    ]);
+
+    assert_eq!(
+        calc_polygon_spans(&arrow, FillRule::EvenOdd),
+        calc_polygon_spans(&arrow, FillRule::NonZero)
+    );
+}
+
+#[test]
+fn spans_are_ordered_and_separate() {
+    let spans = calc_polygon_spans(&star(60), FillRule::EvenOdd);
+
+    for pair in spans.windows(2) {
+        assert!(!pair[0].is_empty());
+        assert!(
+            pair[0].y < pair[1].y || (pair[0].y == pair[1].y && pair[0].x_end + 1 < pair[1].x_start),
+            "{:?} is not before {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn degenerate_polygons_fill_nothing() {
+    let empty = Polygon::new(vec![]);
+    let point = Polygon::from(vec![Point::new(3, 3)]);
+    let segment = Polygon::from(vec![Point::new(0, 0), Point::new(20, 20)]);
+    let flat = Polygon::from(vec![Point::new(0, 5), Point::new(10, 5), Point::new(20, 5)]);
+
+    for polygon in &[empty, point, segment, flat] {
+        assert!(calc_polygon_spans(polygon, FillRule::NonZero).is_empty(), "{:?}", polygon);
+    }
+}
+
+#[test]
+fn large_polygons_are_clipped_to_the_canvas() {
+    let mut canvas = FrameBuffer::new(32, 32);
+    let huge = Polygon::from(vec![
+        Point::new(i32::MIN, i32::MIN),
+        Point::new(i32::MAX, i32::MIN),
+        Point::new(i32::MAX, i32::MAX),
+        Point::new(i32::MIN, i32::MAX),
+    ]);
+
+    fill_polygon(&huge, FillRule::EvenOdd, GREEN, &mut canvas);
+
+    assert!(canvas.pixels().iter().all(|pixel| *pixel == GREEN));
+}
+
+#[test]
+fn filled_polygons() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    let shifted = |polygon: Polygon, offset: Point| {
+        Polygon::new(polygon.vertices.into_iter().map(|vertex| vertex + offset).collect())
+    };
+
+    fill_polygon(&shifted(star(30), Point::new(-32, 32)), FillRule::EvenOdd, GREEN, &mut canvas);
+    fill_polygon(&shifted(star(30), Point::new(32, 32)), FillRule::NonZero, RED, &mut canvas);
+    fill_polygon(
+        &Polygon::from(vec![Point::new(-60, -60), Point::new(-5, -50), Point::new(-30, -30), Point::new(-10, -5)]),
+        FillRule::EvenOdd,
+        GREEN,
+        &mut canvas,
+    );
+    fill_polygon(&Polygon::from(Rect::new(Point::new(10, -10), Point::new(50, -50))), FillRule::NonZero, RED, &mut canvas);
+
+    assert_golden("filled_polygons", &canvas);
+}
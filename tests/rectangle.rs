@@ -0,0 +1,149 @@
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Circle, Line, Point, Polygon, Rect, Span};
+use graphics_rs::midpoint_circle::calc_circle_points;
+use graphics_rs::midpoint_line::calc_line_points;
+use graphics_rs::rectangle::{calc_rect_border_spans, calc_rect_points, calc_rect_spans, draw_rect, Style};
+use graphics_rs::thick_line::{calc_outline_spans, Stroke};
+
+use common::{assert_golden, GREEN, RED};
+
+/// Checks that `points` go round in a closed loop of neighbouring pixels
+/// without visiting a pixel twice.
+fn assert_closed_loop(points: &[Point]) {
+    let unique: HashSet<&Point> = points.iter().collect();
+    assert_eq!(unique.len(), points.len());
+
+    for (i, point) in points.iter().enumerate() {
+        let next = points[(i + 1) % points.len()];
+        let step = ((next.x - point.x).abs(), (next.y - point.y).abs());
+        assert!(step.0 <= 1 && step.1 <= 1, "{:?} to {:?}", point, next);
+    }
+}
+
+#[test]
+fn square_corners_are_four_midpoint_lines() {
+    let rect = Rect::new(Point::new(-30, 12), Point::new(45, -7));
+    let points = calc_rect_points(rect, 0);
+
+    let corners = rect.corners();
+    let expected: HashSet<Point> = (0..4)
+        .flat_map(|i| calc_line_points(Line::new(corners[i], corners[(i + 1) % 4])))
+        .collect();
+
+    assert_eq!(points.iter().copied().collect::<HashSet<_>>(), expected);
+    assert_eq!(points[0], rect.min);
+    assert_closed_loop(&points);
+}
+
+#[test]
+fn rounded_corners_stay_within_the_rectangle() {
+    let rect = Rect::new(Point::new(5, 3), Point::new(84, 50));
+    for radius in 1..30 {
+        let points = calc_rect_points(rect, radius);
+        assert_closed_loop(&points);
+        assert!(points.iter().all(|point| rect.contains(*point)), "radius {}", radius);
+
+        // The corners are cut off, but the middle of every side is kept.
+        for corner in rect.corners().iter() {
+            assert!(!points.contains(corner), "radius {}", radius);
+        }
+        assert!(points.contains(&Point::new(44, 3)) && points.contains(&Point::new(44, 50)));
+        assert!(points.contains(&Point::new(5, 26)) && points.contains(&Point::new(84, 26)));
+    }
+}
+
+#[test]
+fn the_radius_is_limited_to_half_of_the_shorter_side() {
+    let rect = Rect::new(Point::new(0, 0), Point::new(40, 20));
+    assert_eq!(calc_rect_points(rect, 10), calc_rect_points(rect, 500));
+    assert_eq!(calc_rect_points(rect, 0), calc_rect_points(rect, -4));
+
+    // A square rounded all the way is the Mid-Point circle.
+    let square = Rect::from_center(Point::new(7, -3), 30, 30);
+    let circle = calc_circle_points(Circle::new(Point::new(7, -3), 15));
+    let points: HashSet<Point> = calc_rect_points(square, 15).into_iter().collect();
+    assert_eq!(points, circle.into_iter().collect());
+}
+
+#[test]
+fn degenerate_rectangles_have_points() {
+    let dot = Point::new(3, 4);
+    assert_eq!(calc_rect_points(Rect::new(dot, dot), 5), vec![dot]);
+
+    let line = calc_rect_points(Rect::new(Point::new(0, 0), Point::new(9, 0)), 0);
+    assert_eq!(line.iter().collect::<HashSet<_>>().len(), 10);
+}
+
+#[test]
+fn spans_fill_the_outline() {
+    let rect = Rect::new(Point::new(-40, -25), Point::new(33, 18));
+    for radius in 0..25 {
+        let outline = calc_rect_points(rect, radius);
+        let spans = calc_rect_spans(rect, radius);
+
+        let mut extents: HashMap<i32, (i32, i32)> = HashMap::new();
+        for point in &outline {
+            let extent = extents.entry(point.y).or_insert((point.x, point.x));
+            *extent = (extent.0.min(point.x), extent.1.max(point.x));
+        }
+        assert_eq!(spans.len(), 44);
+        for span in &spans {
+            assert_eq!(Some(&(span.x_start, span.x_end)), extents.get(&span.y), "row {}", span.y);
+        }
+    }
+
+    let spans = calc_rect_spans(rect, 0);
+    assert!(spans.iter().all(|span| *span == Span::new(span.y, -40, 33)));
+}
+
+fn pixels(spans: &[Span]) -> HashSet<Point> {
+    spans.iter().flat_map(|span| (span.x_start..=span.x_end).map(move |x| Point::new(x, span.y))).collect()
+}
+
+#[test]
+fn square_borders_are_stroked_outlines() {
+    let rect = Rect::new(Point::new(-30, -12), Point::new(45, 17));
+    for width in 1..=15 {
+        let inset = (width as i32 - 1) / 2;
+        let inner = Rect::new(rect.min + Point::new(inset, inset), rect.max - Point::new(inset, inset));
+        let stroked = calc_outline_spans(&Polygon::from(inner), &Stroke::new(width));
+        assert_eq!(pixels(&calc_rect_border_spans(rect, 0, width)), pixels(&stroked), "width {}", width);
+    }
+}
+
+#[test]
+fn rounded_borders_grow_into_the_rectangle() {
+    let rect = Rect::new(Point::new(5, 3), Point::new(84, 50));
+    for radius in (0..30).step_by(3) {
+        let filled = pixels(&calc_rect_spans(rect, radius));
+        let outline: HashSet<Point> = calc_rect_points(rect, radius).into_iter().collect();
+        let mut previous = HashSet::new();
+        for width in 1..=24 {
+            let border = pixels(&calc_rect_border_spans(rect, radius, width));
+            assert!(border.is_superset(&outline) && border.is_subset(&filled), "radius {} width {}", radius, width);
+            assert!(border.is_superset(&previous), "radius {} width {}", radius, width);
+            previous = border;
+        }
+        // A border as wide as half of the rectangle fills it.
+        assert_eq!(previous, filled);
+        assert_eq!(calc_rect_border_spans(rect, radius, 0), vec![]);
+    }
+}
+
+#[test]
+fn rectangles() {
+    let mut canvas = FrameBuffer::new(128, 128);
+
+    draw_rect(Rect::new(Point::new(-60, 20), Point::new(-5, 60)), 0, Style::Outline, GREEN, &mut canvas);
+    draw_rect(Rect::new(Point::new(5, 20), Point::new(60, 60)), 12, Style::Outline, GREEN, &mut canvas);
+    draw_rect(Rect::new(Point::new(-52, 28), Point::new(-13, 52)), 8, Style::Border(3), RED, &mut canvas);
+    draw_rect(Rect::new(Point::new(-60, -55), Point::new(-5, -10)), 0, Style::Filled, RED, &mut canvas);
+    draw_rect(Rect::from_center(Point::new(33, -33), 51, 41), 15, Style::Filled, RED, &mut canvas);
+    draw_rect(Rect::from_center(Point::new(33, -33), 51, 41), 15, Style::Outline, GREEN, &mut canvas);
+
+    assert_golden("rectangles", &canvas);
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use graphics_rs::canvas::{Canvas, FrameBuffer};
+use graphics_rs::geometry::{Circle, Line, Point};
+use graphics_rs::midpoint_circle::{draw_circle, draw_flower, Outline};
+use graphics_rs::midpoint_line::draw_line;
+use graphics_rs::seed_fill::{boundary_fill, flood_fill, Connectivity};
+
+use common::{assert_golden, GREEN, RED};
+
+const EMPTY: image::Rgba<u8> = image::Rgba([0, 0, 0, 0]);
+
+fn count(canvas: &FrameBuffer, color: image::Rgba<u8>) -> usize {
+    canvas.pixels().iter().filter(|pixel| **pixel == color).count()
+}
+
+#[test]
+fn four_connected_fill_stays_inside_a_circle() {
+    let mut canvas = FrameBuffer::new(64, 64);
+    draw_circle(Circle::new(Point::new(0, 0), 20), GREEN, &mut canvas);
+
+    let filled = boundary_fill(Point::new(0, 0), GREEN, RED, Connectivity::Four, &mut canvas);
+
+    assert_eq!(filled, count(&canvas, RED));
+    for y in 0..64 {
+        for x in 0..64 {
+            let (dx, dy) = (x as i64 - 32, 32 - y as i64);
+            if canvas.get_pixel(x, y) == RED {
+                assert!(dx * dx + dy * dy < 20 * 20, "({}, {}) is outside of the circle", dx, dy);
+            }
+        }
+    }
+    // The corners are on the other side of the circle.
+    assert_eq!(canvas.get_pixel(0, 0), EMPTY);
+}
+
+#[test]
+fn eight_connected_fill_leaks_through_diagonal_boundaries() {
+    let mut canvas = FrameBuffer::new(64, 64);
+    draw_circle(Circle::new(Point::new(0, 0), 20), GREEN, &mut canvas);
+
+    boundary_fill(Point::new(0, 0), GREEN, RED, Connectivity::Eight, &mut canvas);
+
+    assert_eq!(canvas.get_pixel(0, 0), RED);
+}
+
+#[test]
+fn flood_fill_only_replaces_the_seed_color() {
+    let mut canvas = FrameBuffer::new(32, 32);
+    // Splits the canvas into two halves.
+    draw_line(Line::new(Point::new(0, -15), Point::new(0, 16)), GREEN, &mut canvas);
+    canvas.put_pixel(3, 3, RED);
+
+    let filled = flood_fill(Point::new(-5, 0), RED, Connectivity::Four, &mut canvas);
+
+    assert_eq!(filled, 16 * 32 - 1);
+    assert_eq!(count(&canvas, GREEN), 32);
+    assert_eq!(canvas.get_pixel(31, 31), EMPTY);
+}
+
+#[test]
+fn seeds_that_fill_nothing() {
+    let mut canvas = FrameBuffer::new(16, 16);
+    canvas.put_pixel(8, 8, GREEN);
+
+    assert_eq!(boundary_fill(Point::new(100, 0), GREEN, RED, Connectivity::Four, &mut canvas), 0);
+    assert_eq!(flood_fill(Point::new(0, -100), RED, Connectivity::Eight, &mut canvas), 0);
+    // The seed is on the boundary.
+    assert_eq!(boundary_fill(Point::new(0, 0), GREEN, RED, Connectivity::Four, &mut canvas), 0);
+    // The seed already has the fill color.
+    assert_eq!(flood_fill(Point::new(0, 0), GREEN, Connectivity::Four, &mut canvas), 0);
+    assert_eq!(count(&canvas, RED), 0);
+}
+
+#[test]
+fn large_regions_do_not_overflow_the_stack() {
+    let mut canvas = FrameBuffer::new(800, 800);
+
+    assert_eq!(flood_fill(Point::new(0, 0), RED, Connectivity::Four, &mut canvas), 800 * 800);
+    assert_eq!(boundary_fill(Point::new(0, 0), GREEN, GREEN, Connectivity::Eight, &mut canvas), 800 * 800);
+}
+
+#[test]
+fn flower_petals() {
+    let mut canvas = FrameBuffer::new(128, 128);
+    draw_flower(Point::new(0, 0), 60, Outline::Aliased, GREEN, &mut canvas);
+
+    // The petals between the center and the child circles.
+    for &seed in &[Point::new(15, 2), Point::new(-2, 15), Point::new(-15, -2), Point::new(2, -15)] {
+        assert!(boundary_fill(seed, GREEN, RED, Connectivity::Four, &mut canvas) > 0);
+    }
+
+    assert_golden("flower_petals", &canvas);
+}
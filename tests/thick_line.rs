@@ -0,0 +1,168 @@
+mod common;
+
+use std::collections::HashSet;
+
+use graphics_rs::canvas::FrameBuffer;
+use graphics_rs::geometry::{Line, Point, PointF, Polygon, Rect, Span};
+use graphics_rs::thick_line::{
+    calc_outline_spans, calc_polyline_spans, calc_thick_line_spans, draw_outline, draw_polyline, draw_thick_line, Cap, Join,
+    Stroke,
+};
+
+use common::{assert_golden, GREEN, RED};
+
+fn pixels(spans: &[Span]) -> HashSet<Point> {
+    spans.iter().flat_map(|span| (span.x_start..=span.x_end).map(move |x| Point::new(x, span.y))).collect()
+}
+
+/// The distance of `point` from the segment `line`.
+fn distance(point: Point, line: Line) -> f64 {
+    let (a, b, p) = (PointF::from(line.start), PointF::from(line.end), PointF::from(point));
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0);
+    (p.x - a.x - t * dx).hypot(p.y - a.y - t * dy)
+}
+
+#[test]
+fn straight_lines_are_as_wide_as_the_stroke() {
+    for width in 1..8 {
+        let line = Line::new(Point::new(-5, 3), Point::new(7, 3));
+        let spans = calc_thick_line_spans(line, &Stroke::new(width));
+
+        assert_eq!(spans.len(), width as usize);
+        assert!(spans.iter().all(|span| span.x_start == -5 && span.x_end == 7));
+
+        let square = calc_thick_line_spans(line, &Stroke::new(width).with_cap(Cap::Square));
+        let reach = (width / 2) as i32;
+        assert!(square.iter().all(|span| span.x_start == -5 - reach && span.x_end == 7 + reach), "width {}", width);
+
+        let vertical = pixels(&calc_thick_line_spans(Line::new(Point::new(0, 9), Point::new(0, -9)), &Stroke::new(width)));
+        assert_eq!(vertical.len(), width as usize * 19);
+    }
+}
+
+#[test]
+fn sloped_lines_keep_the_pixels_within_half_the_width() {
+    let lines = [
+        Line::new(Point::new(0, 0), Point::new(40, 13)),
+        Line::new(Point::new(3, -7), Point::new(-12, 30)),
+        Line::new(Point::new(-20, -20), Point::new(20, 20)),
+    ];
+    for &line in &lines {
+        for &width in &[1, 3, 6, 9] {
+            let half_width = f64::from(width) / 2.0;
+            let drawn = pixels(&calc_thick_line_spans(line, &Stroke::new(width).with_cap(Cap::Round)));
+
+            for y in -60..60 {
+                for x in -60..60 {
+                    let point = Point::new(x, y);
+                    let distance = distance(point, line);
+                    if distance < half_width - 1e-6 {
+                        assert!(drawn.contains(&point), "{:?} width {} misses {:?}", line, width, point);
+                    } else if distance > half_width + 1e-6 {
+                        assert!(!drawn.contains(&point), "{:?} width {} has {:?}", line, width, point);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn rectangle_border_grows_inwards() {
+    for &(width, height) in &[(10, 6), (3, 3), (25, 2), (1, 7)] {
+        let rect = Rect::new(Point::new(-4, 2), Point::new(-4 + width, 2 + height));
+        let drawn = pixels(&calc_outline_spans(&Polygon::from(rect), &Stroke::new(2)));
+
+        let mut expected = HashSet::new();
+        for y in rect.min.y..=rect.max.y {
+            for x in rect.min.x..=rect.max.x {
+                let inside = (x - rect.min.x).min(rect.max.x - x).min(y - rect.min.y).min(rect.max.y - y);
+                if inside < 2 {
+                    expected.insert(Point::new(x, y));
+                }
+            }
+        }
+        assert_eq!(drawn, expected, "{}x{}", width, height);
+    }
+}
+
+#[test]
+fn joins_fill_the_outer_corner() {
+    // A right turn at the origin, so the outer corner is to the top left.
+    let points = [Point::new(-20, 0), Point::new(0, 0), Point::new(0, -20)];
+    let corner = |join: Join| pixels(&calc_polyline_spans(&points, &Stroke::new(5).with_join(join)));
+
+    let miter = corner(Join::Miter);
+    let bevel = corner(Join::Bevel);
+    let round = corner(Join::Round);
+
+    // The miter is square, the bevel cuts it off at the diagonal and the round
+    // join lies in between.
+    assert!(miter.contains(&Point::new(2, 2)));
+    assert!(!bevel.contains(&Point::new(2, 2)) && !round.contains(&Point::new(2, 2)));
+    assert!(bevel.contains(&Point::new(1, 1)) && round.contains(&Point::new(1, 2)));
+    assert!(!bevel.contains(&Point::new(1, 2)));
+    assert!(bevel.is_subset(&round) && round.is_subset(&miter));
+
+    // Without a join, the corner is missing.
+    let butt: HashSet<Point> = pixels(&calc_thick_line_spans(Line::new(points[0], points[1]), &Stroke::new(5)))
+        .union(&pixels(&calc_thick_line_spans(Line::new(points[1], points[2]), &Stroke::new(5))))
+        .copied()
+        .collect();
+    assert!(butt.is_subset(&bevel));
+    assert!(!butt.contains(&Point::new(1, 1)));
+}
+
+#[test]
+fn sharp_miters_become_bevels() {
+    let points = [Point::new(-30, 0), Point::new(0, 0), Point::new(-30, 4)];
+    let miter = pixels(&calc_polyline_spans(&points, &Stroke::new(6).with_join(Join::Miter)));
+    let bevel = pixels(&calc_polyline_spans(&points, &Stroke::new(6).with_join(Join::Bevel)));
+
+    assert_eq!(miter, bevel);
+    assert!(miter.iter().all(|point| point.x <= 3));
+}
+
+#[test]
+fn degenerate_strokes() {
+    let line = Line::new(Point::new(2, 2), Point::new(9, -1));
+    assert!(calc_thick_line_spans(line, &Stroke::new(0)).is_empty());
+
+    let dot = Line::new(Point::new(2, 2), Point::new(2, 2));
+    assert!(calc_thick_line_spans(dot, &Stroke::new(4)).is_empty());
+    assert_eq!(pixels(&calc_thick_line_spans(dot, &Stroke::new(4).with_cap(Cap::Square))).len(), 16);
+    assert_eq!(pixels(&calc_thick_line_spans(dot, &Stroke::new(2).with_cap(Cap::Round))).len(), 5);
+
+    // Repeated points are skipped, also the closing one of an outline.
+    let square = [Point::new(0, 0), Point::new(8, 0), Point::new(8, 0), Point::new(8, 8), Point::new(0, 8)];
+    let mut closed = square.to_vec();
+    closed.push(Point::new(0, 0));
+    assert_eq!(
+        calc_outline_spans(&Polygon::new(square.to_vec()), &Stroke::new(3)),
+        calc_outline_spans(&Polygon::new(closed), &Stroke::new(3))
+    );
+}
+
+#[test]
+fn caps_and_joins_image() {
+    let mut canvas = FrameBuffer::new(96, 96);
+
+    let caps = [Cap::Butt, Cap::Square, Cap::Round];
+    for (i, &cap) in caps.iter().enumerate() {
+        let y = 36 - 12 * i as i32;
+        draw_thick_line(Line::new(Point::new(-38, y), Point::new(-8, y + 6)), &Stroke::new(7).with_cap(cap), GREEN, &mut canvas);
+    }
+
+    let joins = [Join::Miter, Join::Bevel, Join::Round];
+    for (i, &join) in joins.iter().enumerate() {
+        let x = 6 + 14 * i as i32;
+        let zigzag = [Point::new(x, 38), Point::new(x + 8, 22), Point::new(x, 6)];
+        draw_polyline(&zigzag, &Stroke::new(5).with_join(join), RED, &mut canvas);
+    }
+
+    let outline = Polygon::new(vec![Point::new(-36, -40), Point::new(36, -36), Point::new(10, -10), Point::new(-20, -24)]);
+    draw_outline(&outline, &Stroke::new(3), GREEN, &mut canvas);
+
+    assert_golden("caps_and_joins", &canvas);
+}
@@ -0,0 +1,95 @@
+use graphics_rs::geometry::{Line, Point, PointF, Polygon};
+use graphics_rs::midpoint_line::Zone;
+use graphics_rs::transform::Transform2D;
+
+fn assert_close(a: PointF, b: PointF) {
+    assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9, "{:?} is not {:?}", a, b);
+}
+
+#[test]
+fn basic_transformations() {
+    let point = Point::new(3, 4);
+
+    assert_eq!(Transform2D::identity().apply(point), point);
+    assert_eq!(Transform2D::translate(-5.0, 2.0).apply(point), Point::new(-2, 6));
+    assert_eq!(Transform2D::rotate(90.0).apply(point), Point::new(-4, 3));
+    assert_eq!(Transform2D::rotate(-270.0).apply(point), Point::new(-4, 3));
+    assert_eq!(Transform2D::scale(2.0, -1.0).apply(point), Point::new(6, -4));
+    assert_eq!(Transform2D::shear(1.0, 0.0).apply(point), Point::new(7, 4));
+    assert_eq!(Transform2D::reflect(0.0).apply(point), Point::new(3, -4));
+    assert_eq!(Transform2D::reflect(45.0).apply(point), Point::new(4, 3));
+    assert_close(Transform2D::rotate(45.0).apply_f(PointF::new(2.0, 0.0)), PointF::new(2f64.sqrt(), 2f64.sqrt()));
+}
+
+#[test]
+fn quarter_turns_are_exact() {
+    let turn = Transform2D::rotate(90.0);
+
+    assert_eq!(turn.matrix(), [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(turn.apply(Point::new(i32::MAX, 0)), Point::new(0, i32::MAX));
+}
+
+#[test]
+fn composition_applies_in_order() {
+    let point = PointF::new(1.0, 2.0);
+    let rotate = Transform2D::rotate(30.0);
+    let translate = Transform2D::translate(10.0, 0.0);
+
+    let composed = rotate.then(&translate);
+    assert_close(composed.apply_f(point), translate.apply_f(rotate.apply_f(point)));
+    assert_eq!(composed, translate * rotate);
+    assert_ne!(composed, translate.then(&rotate));
+}
+
+#[test]
+fn inverse_undoes_the_transformation() {
+    let transform = Transform2D::rotate(33.0)
+        .then(&Transform2D::scale(2.0, 0.5))
+        .then(&Transform2D::shear(0.3, -1.2))
+        .then(&Transform2D::translate(-7.0, 12.5));
+    let inverse = transform.inverse().unwrap();
+
+    for &(x, y) in &[(0.0, 0.0), (13.5, -2.0), (-1000.0, 250.0)] {
+        let point = PointF::new(x, y);
+        assert_close(inverse.apply_f(transform.apply_f(point)), point);
+        assert_close(transform.apply_f(inverse.apply_f(point)), point);
+    }
+
+    assert_eq!(Transform2D::scale(0.0, 1.0).inverse(), None);
+}
+
+#[test]
+fn lines_and_polygons() {
+    let transform = Transform2D::translate(1.0, -1.0);
+
+    assert_eq!(
+        transform.apply_line(Line::new(Point::new(0, 0), Point::new(5, 5))),
+        Line::new(Point::new(1, -1), Point::new(6, 4))
+    );
+    assert_eq!(
+        transform.apply_polygon(&Polygon::from(vec![Point::new(0, 0), Point::new(2, 0), Point::new(0, 2)])),
+        Polygon::from(vec![Point::new(1, -1), Point::new(3, -1), Point::new(1, 1)])
+    );
+}
+
+#[test]
+fn zone_transforms_round_trip() {
+    let zones = [
+        Zone::ZERO,
+        Zone::ONE,
+        Zone::TWO,
+        Zone::THREE,
+        Zone::FOUR,
+        Zone::FIVE,
+        Zone::SIX,
+        Zone::SEVEN,
+    ];
+    let points = [Point::new(7, 3), Point::new(-2, 11), Point::new(0, -5), Point::new(-9, -9)];
+
+    for zone in &zones {
+        let (to, from) = (zone.to_zone_zero(), zone.from_zone_zero());
+        for point in &points {
+            assert_eq!(from.apply(to.apply(*point)), *point, "zone {}", zone.name());
+        }
+    }
+}
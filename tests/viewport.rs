@@ -0,0 +1,140 @@
+mod common;
+
+use graphics_rs::canvas::{Canvas, FrameBuffer};
+use graphics_rs::cli::take_viewport_arg;
+use graphics_rs::geometry::{Circle, Line, Point, PointF, Rect};
+use graphics_rs::midpoint_circle::fill_circle;
+use graphics_rs::midpoint_line::draw_line;
+use graphics_rs::seed_fill::{flood_fill, Connectivity};
+use graphics_rs::viewport::{Viewport, YAxis};
+
+use common::{GREEN, RED};
+
+fn drawn(canvas: &FrameBuffer, color: image::Rgba<u8>) -> Vec<(u32, u32)> {
+    (0..canvas.height())
+        .flat_map(|y| (0..canvas.width()).map(move |x| (x, y)))
+        .filter(|&(x, y)| canvas.get_pixel(x, y) == color)
+        .collect()
+}
+
+#[test]
+fn centered_origin_with_y_up() {
+    let viewport = Viewport::centered(64, 32);
+
+    assert_eq!(viewport.to_device(Point::new(0, 0)), Point::new(32, 16));
+    assert_eq!(viewport.to_device(Point::new(5, 3)), Point::new(37, 13));
+    assert_eq!(viewport.to_device(Point::new(-32, -15)), Point::new(0, 31));
+    assert_eq!(viewport.to_world(Point::new(0, 0)), Some(Point::new(-32, 16)));
+    assert_eq!(FrameBuffer::new(64, 32).viewport(), viewport);
+}
+
+#[test]
+fn screen_coordinates_are_device_coordinates() {
+    let viewport = Viewport::screen();
+
+    for &point in &[Point::new(0, 0), Point::new(7, 3), Point::new(-4, 90)] {
+        assert_eq!(viewport.to_device(point), point);
+        assert_eq!(viewport.to_world(point), Some(point));
+    }
+
+    let mut buffer = FrameBuffer::new(16, 8);
+    let canvas = viewport.canvas(&mut buffer);
+    assert_eq!(canvas.world_bounds(), Rect::new(Point::new(0, 0), Point::new(15, 7)));
+}
+
+#[test]
+fn world_and_device_round_trip() {
+    let viewport = Viewport::centered(100, 80).with_zoom(3.0).with_origin(PointF::new(10.0, 70.0));
+
+    for x in -20..20 {
+        for y in -20..20 {
+            let point = Point::new(x, y);
+            assert_eq!(viewport.to_world(viewport.to_device(point)), Some(point));
+        }
+    }
+}
+
+#[test]
+fn window_fills_the_device_rectangle() {
+    let window = Rect::new(Point::new(-10, -5), Point::new(9, 4));
+    let device = Rect::new(Point::new(0, 0), Point::new(39, 19));
+
+    let up = Viewport::from_window(window, device, YAxis::Up);
+    assert_eq!(up.device_pixels(Point::new(-10, -5)), ([0, 2], [18, 20]));
+    assert_eq!(up.device_pixels(Point::new(9, 4)), ([38, 40], [0, 2]));
+
+    let down = Viewport::from_window(window, device, YAxis::Down);
+    assert_eq!(down.device_pixels(Point::new(-10, -5)), ([0, 2], [0, 2]));
+    assert_eq!(down.device_pixels(Point::new(9, 4)), ([38, 40], [18, 20]));
+}
+
+#[test]
+fn zoomed_world_pixels_cover_blocks() {
+    let mut buffer = FrameBuffer::new(32, 32);
+    let mut canvas = Viewport::centered(32, 32).with_zoom(4.0).canvas(&mut buffer);
+
+    assert!(canvas.put_world_pixel(Point::new(1, 1), GREEN));
+    assert_eq!(canvas.world_bounds(), Rect::new(Point::new(-4, -4), Point::new(4, 4)));
+
+    let expected: Vec<(u32, u32)> = (10..14).flat_map(|y| (18..22).map(move |x| (x, y))).collect();
+    assert_eq!(drawn(&buffer, GREEN), expected);
+}
+
+#[test]
+fn zoomed_out_world_pixels_keep_one_pixel() {
+    let viewport = Viewport::centered(32, 32).with_zoom(0.25);
+
+    for x in -8..8 {
+        let ([x_start, x_end], [y_start, y_end]) = viewport.device_pixels(Point::new(x, 0));
+        assert_eq!((x_end - x_start, y_end - y_start), (1, 1));
+    }
+}
+
+#[test]
+fn y_down_mirrors_the_drawing() {
+    let line = Line::new(Point::new(-20, -3), Point::new(25, 14));
+
+    let mut up = FrameBuffer::new(64, 64);
+    draw_line(line, GREEN, &mut up);
+
+    let mut down = FrameBuffer::new(64, 64);
+    let viewport = Viewport::centered(64, 64).with_y_axis(YAxis::Down);
+    draw_line(line, GREEN, &mut viewport.canvas(&mut down));
+
+    // The origin is on row 32, so row y mirrors onto row 64 - y.
+    let mut mirrored: Vec<(u32, u32)> = drawn(&up, GREEN).into_iter().map(|(x, y)| (x, 64 - y)).collect();
+    mirrored.sort_by_key(|&(x, y)| (y, x));
+    assert_eq!(mirrored, drawn(&down, GREEN));
+}
+
+#[test]
+fn fills_go_through_the_viewport() {
+    let mut plain = FrameBuffer::new(64, 64);
+    fill_circle(Circle::new(Point::new(0, 0), 6), GREEN, &mut plain);
+
+    let mut buffer = FrameBuffer::new(64, 64);
+    let mut zoomed = Viewport::centered(64, 64).with_zoom(2.0).canvas(&mut buffer);
+    fill_circle(Circle::new(Point::new(0, 0), 6), GREEN, &mut zoomed);
+
+    // The seed is a world point as well.
+    let filled = flood_fill(Point::new(0, 0), RED, Connectivity::Four, &mut zoomed);
+
+    assert_eq!(filled, 4 * drawn(&plain, GREEN).len());
+    assert_eq!(drawn(&buffer, RED).len(), filled);
+    assert!(drawn(&buffer, GREEN).is_empty());
+}
+
+#[test]
+fn viewport_arguments() {
+    let mut args: Vec<String> = ["bin", "40", "--origin", "10,70.5", "--zoom", "2", "--y-down", "7"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    let viewport = take_viewport_arg(&mut args, 100, 80);
+
+    assert_eq!(args, vec!["bin", "40", "7"]);
+    assert_eq!(viewport, Viewport::centered(100, 80).with_origin(PointF::new(10.0, 70.5)).with_zoom(2.0).with_y_axis(YAxis::Down));
+
+    let mut args = vec!["bin".to_string()];
+    assert_eq!(take_viewport_arg(&mut args, 100, 80), Viewport::centered(100, 80));
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use std::collections::HashMap;
+
+use graphics_rs::canvas::{blend, Canvas, FrameBuffer};
+use graphics_rs::geometry::{Point, PointF};
+use graphics_rs::wu_line::{calc_wu_points, draw_line_wu};
+use image::Rgba;
+
+use common::assert_golden;
+
+fn column_sums(points: &[(Point, f64)]) -> HashMap<i32, f64> {
+    let mut sums = HashMap::new();
+    for (point, coverage) in points {
+        *sums.entry(point.x).or_insert(0.0) += coverage;
+    }
+    sums
+}
+
+#[test]
+fn integer_horizontal_line() {
+    let points = calc_wu_points(Point::new(-2, 3), Point::new(2, 3));
+
+    let expected = vec![
+        (Point::new(-2, 3), 0.5),
+        (Point::new(-1, 3), 1.0),
+        (Point::new(0, 3), 1.0),
+        (Point::new(1, 3), 1.0),
+        (Point::new(2, 3), 0.5),
+    ];
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn columns_share_full_coverage() {
+    let points = calc_wu_points(PointF::new(-10.3, 2.2), PointF::new(17.6, -6.9));
+
+    for (x, sum) in column_sums(&points) {
+        let expected = match x {
+            -10 => 0.8,
+            18 => 0.1,
+            _ => 1.0,
+        };
+        assert!((sum - expected).abs() < 1e-9, "column {} has {}", x, sum);
+    }
+    assert!(points.iter().all(|&(_, coverage)| coverage > 0.0 && coverage <= 1.0));
+}
+
+#[test]
+fn pixels_lie_next_to_the_line() {
+    let (a, b) = (PointF::new(0.0, 0.0), PointF::new(13.0, 5.0));
+    for (point, coverage) in calc_wu_points(a, b) {
+        // The distance along Y from the line, which splits the coverage.
+        let distance = (f64::from(point.y) - f64::from(point.x) * 5.0 / 13.0).abs();
+        assert!(distance < 1.0, "{:?} is off the line", point);
+        if point.x > 0 && point.x < 13 {
+            assert!((coverage - (1.0 - distance)).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn steep_lines_are_transposed() {
+    let flat = calc_wu_points(PointF::new(1.2, -3.0), PointF::new(9.7, 0.4));
+    let steep = calc_wu_points(PointF::new(-3.0, 1.2), PointF::new(0.4, 9.7));
+
+    let transposed: Vec<(Point, f64)> = flat.iter().map(|&(p, c)| (Point::new(p.y, p.x), c)).collect();
+    assert_eq!(steep, transposed);
+}
+
+#[test]
+fn direction_does_not_matter() {
+    let mut forward = calc_wu_points(PointF::new(-4.5, 2.25), PointF::new(6.0, -1.0));
+    let mut backward = calc_wu_points(PointF::new(6.0, -1.0), PointF::new(-4.5, 2.25));
+
+    let key = |&(p, _): &(Point, f64)| (p.x, p.y);
+    forward.sort_by_key(key);
+    backward.sort_by_key(key);
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn degenerate_lines() {
+    assert!(calc_wu_points(Point::new(4, 4), Point::new(4, 4)).is_empty());
+    assert!(calc_wu_points(PointF::new(f64::NAN, 0.0), PointF::new(1.0, 1.0)).is_empty());
+
+    // Both end points inside one pixel: it gets the share of the column the
+    // line spans.
+    let short = calc_wu_points(PointF::new(1.8, 0.0), PointF::new(2.3, 0.0));
+    assert_eq!(short.len(), 1);
+    assert_eq!(short[0].0, Point::new(2, 0));
+    assert!((short[0].1 - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn blending() {
+    let white = Rgba([255, 255, 255, 255]);
+    let red = Rgba([255, 0, 0, 255]);
+    let clear = Rgba([0, 0, 0, 0]);
+
+    assert_eq!(blend(red, white, 1.0), red);
+    assert_eq!(blend(red, white, 0.0), white);
+    assert_eq!(blend(red, white, 0.5), Rgba([255, 128, 128, 255]));
+    assert_eq!(blend(red, clear, 0.5), Rgba([255, 0, 0, 128]));
+    assert_eq!(blend(Rgba([0, 0, 255, 128]), white, 1.0), Rgba([127, 127, 255, 255]));
+    assert_eq!(blend(red, clear, f64::NAN), clear);
+
+    let mut canvas = FrameBuffer::new(4, 4);
+    canvas.clear(white);
+    assert!(canvas.blend_pixel(1, 2, red, 0.25));
+    assert!(!canvas.blend_pixel(4, 0, red, 1.0));
+    assert_eq!(canvas.get_pixel(1, 2), Rgba([255, 191, 191, 255]));
+}
+
+#[test]
+fn wu_lines_image() {
+    let mut canvas = FrameBuffer::new(64, 64);
+    canvas.clear(Rgba([255, 255, 255, 255]));
+
+    let black = Rgba([0, 0, 0, 255]);
+    draw_line_wu(PointF::new(-30.0, -28.0), PointF::new(29.5, -10.2), black, &mut canvas);
+    draw_line_wu(PointF::new(-25.3, 30.0), PointF::new(-5.0, -20.7), black, &mut canvas);
+    draw_line_wu(PointF::new(28.0, 2.0), PointF::new(-22.0, 22.0), Rgba([255, 0, 0, 255]), &mut canvas);
+    draw_line_wu(PointF::new(18.0, 31.0), PointF::new(8.0, -28.0), Rgba([0, 0, 255, 160]), &mut canvas);
+
+    assert_golden("wu_lines", &canvas);
+}
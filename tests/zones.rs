@@ -0,0 +1,115 @@
+//! Property tests for the zone mapping of the midpoint line, checked for every
+//! integer direction in a square around the start point.
+
+use graphics_rs::geometry::{Line, Point};
+use graphics_rs::midpoint_line::{calc_line_midpoint, calc_line_points, convert_zone, Zone};
+
+const RANGE: i32 = 24;
+
+fn every_direction() -> impl Iterator<Item = Line> {
+    let start = Point::new(7, -3);
+    (-RANGE..=RANGE).flat_map(move |dx| (-RANGE..=RANGE).map(move |dy| Line::new(start, start + Point::new(dx, dy))))
+}
+
+#[test]
+fn zone_zero_line_is_in_zone_zero() {
+    for line in every_direction() {
+        let zero_line = line.convert_to_zone_zero();
+        let delta = zero_line.delta();
+
+        assert!(
+            delta.y >= 0 && delta.y <= delta.x,
+            "{:?} in zone {} converted to {:?}",
+            line,
+            line.zone().name(),
+            zero_line
+        );
+        assert_eq!(zero_line.zone(), Zone::ZERO, "{:?}", line);
+    }
+}
+
+#[test]
+fn conversion_round_trips_the_end_points() {
+    for line in every_direction() {
+        let zero_line = line.convert_to_zone_zero();
+        let mut ends = [zero_line.start, zero_line.end];
+        convert_zone(&mut ends, &line.zone());
+
+        assert_eq!(ends, [line.start, line.end], "zone {}", line.zone().name());
+    }
+}
+
+#[test]
+fn rasterized_line_runs_from_start_to_end() {
+    for line in every_direction() {
+        let zone = line.zone();
+        let mut points = calc_line_midpoint(line.convert_to_zone_zero());
+        convert_zone(&mut points, &zone);
+
+        let delta = line.delta();
+        assert_eq!(points.len() as i32, delta.x.abs().max(delta.y.abs()) + 1, "{:?}", line);
+        assert_eq!(points.first(), Some(&line.start), "{:?}", line);
+        assert_eq!(points.last(), Some(&line.end), "{:?}", line);
+
+        for pair in points.windows(2) {
+            let step = pair[1] - pair[0];
+            assert!(step.x.abs() <= 1 && step.y.abs() <= 1, "{:?} has a gap at {:?}", line, pair);
+        }
+    }
+}
+
+#[test]
+fn boundaries_belong_to_the_zone_that_ends_there() {
+    let zone = |x, y| Line::new(Point::new(0, 0), Point::new(x, y)).zone();
+
+    assert_eq!(zone(0, 0), Zone::ZERO);
+    assert_eq!(zone(10, 0), Zone::ZERO);
+    assert_eq!(zone(10, 10), Zone::ZERO);
+    assert_eq!(zone(0, 10), Zone::ONE);
+    assert_eq!(zone(-10, 10), Zone::TWO);
+    assert_eq!(zone(-10, 0), Zone::THREE);
+    assert_eq!(zone(-10, -10), Zone::FOUR);
+    assert_eq!(zone(0, -10), Zone::FIVE);
+    assert_eq!(zone(10, -10), Zone::SIX);
+    assert_eq!(zone(10, -9), Zone::SEVEN);
+}
+
+#[test]
+fn axis_aligned_lines_are_drawn() {
+    let points = calc_line_points(Line::new(Point::new(0, 0), Point::new(0, -3)));
+    assert_eq!(points, vec![Point::new(0, 0), Point::new(0, -1), Point::new(0, -2), Point::new(0, -3)]);
+
+    let points = calc_line_points(Line::new(Point::new(0, 0), Point::new(-3, 0)));
+    assert_eq!(points, vec![Point::new(0, 0), Point::new(-1, 0), Point::new(-2, 0), Point::new(-3, 0)]);
+}
+
+#[test]
+fn extreme_end_points_do_not_overflow() {
+    let (min, max) = (i32::MIN, i32::MAX);
+    assert_eq!(Line::new(Point::new(min, 0), Point::new(max, 1)).zone(), Zone::ZERO);
+    assert_eq!(Line::new(Point::new(0, min), Point::new(1, max)).zone(), Zone::ONE);
+    assert_eq!(Line::new(Point::new(max, max), Point::new(min, min)).zone(), Zone::FOUR);
+
+    let corner = Line::new(Point::new(max - 3, max - 1), Point::new(max, max));
+    assert_eq!(
+        calc_line_midpoint(corner),
+        vec![Point::new(max - 3, max - 1), Point::new(max - 2, max - 1), Point::new(max - 1, max), Point::new(max, max)]
+    );
+}
+
+#[test]
+fn zone_conversion_agrees_with_the_transformations() {
+    let (min, max) = (i32::MIN, i32::MAX);
+    let points = [Point::new(7, -3), Point::new(min, max), Point::new(max, min), Point::new(min, min)];
+
+    for line in every_direction().filter(|line| line.start != line.end) {
+        let zone = line.zone();
+        let mut converted = points;
+        convert_zone(&mut converted, &zone);
+
+        let transform = zone.from_zone_zero();
+        let expected: Vec<Point> = points.iter().map(|point| transform.apply(*point)).collect();
+        assert_eq!(converted.to_vec(), expected, "zone {}", zone.name());
+        assert_eq!(line.convert_to_zone_zero(), zone.to_zone_zero().apply_line(line), "{:?}", line);
+    }
+}